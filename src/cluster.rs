@@ -0,0 +1,147 @@
+use super::Name;
+use smallvec::SmallVec;
+use std::cmp::Ordering;
+use std::collections::HashMap;
+
+/// Groups names which may represent the same person, returning the indices
+/// of the names in each group.
+///
+/// Unlike `consistent_with` (and `==`), which is not transitive, every name
+/// in a group is consistent with every other. Names are first bucketed by
+/// `hash`, then grouped starting from the most complete. A less complete name
+/// consistent with more than one group, as "J. Doe" may be with both "Jane
+/// Doe" and "John Doe", is not merged into either, but grouped separately
+/// with any similarly ambiguous names.
+///
+/// Names equally complete are grouped in order of `display_full`, then of
+/// their index, so groups depend on the order of the input only among names
+/// displayed identically (which may still differ, e.g. in their nicknames).
+/// Indices within each group are in ascending order, as are the groups by
+/// their first index.
+///
+/// ```
+/// use human_name::{cluster, Name};
+///
+/// let names: Vec<Name> = ["Jane Doe", "J. Doe", "John Doe", "Jane M. Doe", "John Smith"]
+///     .iter()
+///     .map(|name| Name::parse(name).unwrap())
+///     .collect();
+///
+/// assert_eq!(vec![vec![0, 3], vec![1], vec![2], vec![4]], cluster(&names));
+/// ```
+pub fn cluster(names: &[Name]) -> Vec<Vec<usize>> {
+    let mut buckets: HashMap<u64, Vec<usize>> = HashMap::new();
+    for (i, name) in names.iter().enumerate() {
+        buckets.entry(name.hash).or_insert_with(Vec::new).push(i);
+    }
+
+    let mut clusters = Vec::with_capacity(buckets.len());
+    for (_, mut bucket) in buckets {
+        bucket.sort_by(|&a, &b| more_complete_first(&names[a], &names[b]).then(a.cmp(&b)));
+        cluster_bucket(names, &bucket, &mut clusters);
+    }
+
+    for cluster in &mut clusters {
+        cluster.sort();
+    }
+    clusters.sort();
+    clusters
+}
+
+// Expects `indices` ordered from most to least complete
+fn cluster_bucket(names: &[Name], indices: &[usize], clusters: &mut Vec<Vec<usize>>) {
+    let start = clusters.len();
+    let mut ambiguous = Vec::new();
+
+    for &i in indices {
+        let name = &names[i];
+        let consistent: SmallVec<[usize; 2]> = (start..clusters.len())
+            .filter(|&c| clusters[c].iter().all(|&j| name.consistent_with(&names[j])))
+            .take(2)
+            .collect();
+
+        match consistent.len() {
+            0 => clusters.push(vec![i]),
+            1 => clusters[consistent[0]].push(i),
+            _ => ambiguous.push(i),
+        }
+    }
+
+    // The first ambiguous name always starts a new cluster, so this ends
+    if !ambiguous.is_empty() {
+        cluster_bucket(names, &ambiguous, clusters);
+    }
+}
+
+// Ties are broken by text rather than input order, so that only names with
+// identical text are ever ordered by their position in the input
+fn more_complete_first(a: &Name, b: &Name) -> Ordering {
+    fn completeness(name: &Name) -> (usize, usize, usize, bool) {
+        (
+            name.initials().chars().count(),
+            name.word_indices_in_initials.len(),
+            name.surname_words(),
+            name.suffix().is_some(),
+        )
+    }
+
+    completeness(b)
+        .cmp(&completeness(a))
+        .then_with(|| a.display_full().cmp(b.display_full()))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn parse_all(names: &[&str]) -> Vec<Name> {
+        names
+            .iter()
+            .map(|name| Name::parse(name).unwrap())
+            .collect()
+    }
+
+    #[test]
+    fn bridge_names() {
+        let names = parse_all(&["J. Doe", "Jane Doe", "John Doe", "J. Doe", "Jane A. Doe"]);
+        assert_eq!(vec![vec![0, 3], vec![1, 4], vec![2]], cluster(&names));
+    }
+
+    #[test]
+    fn unambiguous_initials() {
+        let names = parse_all(&["J. Doe", "Jane Doe", "Jane Doe", "Jim Smith"]);
+        assert_eq!(vec![vec![0, 1, 2], vec![3]], cluster(&names));
+    }
+
+    #[test]
+    fn input_order() {
+        let forward = [
+            "J. Doe",
+            "Jane Doe",
+            "John Doe",
+            "Jon Doe",
+            "J. M. Doe",
+            "Jane M. Doe",
+        ];
+        let mut backward = forward;
+        backward.reverse();
+
+        let last = forward.len() - 1;
+        let mut reversed: Vec<Vec<usize>> = cluster(&parse_all(&backward))
+            .into_iter()
+            .map(|cluster| {
+                let mut cluster: Vec<usize> = cluster.into_iter().map(|i| last - i).collect();
+                cluster.sort();
+                cluster
+            })
+            .collect();
+        reversed.sort();
+
+        assert_eq!(cluster(&parse_all(&forward)), reversed);
+    }
+
+    #[test]
+    fn empty() {
+        assert!(cluster(&[]).is_empty());
+    }
+}
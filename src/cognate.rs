@@ -0,0 +1,98 @@
+use super::utils::to_ascii;
+use phf;
+
+// Whether two given names are equivalents in different European languages,
+// like "John", "Juan" and "Giovanni"
+pub fn are_cognates(a: &str, b: &str) -> bool {
+    match (cognate_groups(a), cognate_groups(b)) {
+        (Some(a), Some(b)) => a.iter().any(|group| b.contains(group)),
+        _ => false,
+    }
+}
+
+fn cognate_groups(name: &str) -> Option<&'static [u16]> {
+    COGNATE_GROUPS_BY_NAME.get(&*to_ascii(name)).cloned()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn cognates() {
+        assert!(are_cognates("John", "Juan"));
+        assert!(are_cognates("Giovanni", "Ivan"));
+        assert!(are_cognates("Seán", "Jean"));
+        assert!(are_cognates("João", "Johann"));
+        assert!(are_cognates("Guillaume", "William"));
+        assert!(are_cognates("Catherine", "Ekaterina"));
+        assert!(are_cognates("István", "Stephen"));
+        assert!(are_cognates("Jean", "Jane"));
+        assert!(are_cognates("Jan", "Johann"));
+        assert!(are_cognates("Joan", "Juan"));
+    }
+
+    #[test]
+    fn not_cognates() {
+        assert!(!are_cognates("John", "Jane"));
+        assert!(!are_cognates("Juan", "Juana"));
+        assert!(!are_cognates("Peter", "Paul"));
+        assert!(!are_cognates("John", "Xander"));
+        assert!(!are_cognates("Xander", "Xander"));
+        assert!(!are_cognates("Juana", "Ivan"));
+        assert!(!are_cognates("Johanna", "Sean"));
+        assert!(!are_cognates("Jean", "Pierre"));
+        assert!(!are_cognates("Jan", "Anna"));
+        assert!(!are_cognates("Michele", "Michael"));
+    }
+}
+
+// Names are grouped by number, one group per line. A few names are both
+// masculine and feminine (e.g. "Jean", French for John but also an English
+// form of Jane), and so belong to both groups. Others with no feminine group
+// here to belong to (e.g. "Michele", Italian for Michael but French for
+// Michelle) are left out.
+static COGNATE_GROUPS_BY_NAME: phf::Map<&'static str, &'static [u16]> = phf_map! {
+    "John" => &[1], "Johann" => &[1], "Johannes" => &[1], "Johan" => &[1], "Jean" => &[1, 2], "Juan" => &[1], "Joao" => &[1], "Giovanni" => &[1], "Gianni" => &[1], "Ivan" => &[1], "Iwan" => &[1], "Sean" => &[1], "Ian" => &[1], "Iain" => &[1], "Eoin" => &[1], "Evan" => &[1], "Jan" => &[1, 2], "Jens" => &[1], "Hans" => &[1], "Janos" => &[1], "Jovan" => &[1], "Ioannis" => &[1], "Yannis" => &[1], "Ioan" => &[1], "Ion" => &[1], "Juhani" => &[1], "Yann" => &[1],
+    "Jane" => &[2], "Joan" => &[1, 2], "Jeanne" => &[2], "Juana" => &[2], "Giovanna" => &[2], "Johanna" => &[2], "Joanna" => &[2], "Joana" => &[2], "Janina" => &[2], "Jana" => &[2], "Ivana" => &[2], "Siobhan" => &[2],
+    "Peter" => &[3], "Pierre" => &[3], "Pedro" => &[3], "Pietro" => &[3], "Piotr" => &[3], "Petr" => &[3], "Petros" => &[3], "Pieter" => &[3], "Per" => &[3], "Pere" => &[3], "Peadar" => &[3], "Petar" => &[3], "Pyotr" => &[3], "Pekka" => &[3],
+    "Paul" => &[4], "Pablo" => &[4], "Paolo" => &[4], "Paulo" => &[4], "Pavel" => &[4], "Pawel" => &[4], "Pal" => &[4], "Pau" => &[4],
+    "Michael" => &[5], "Michel" => &[5], "Miguel" => &[5], "Mikhail" => &[5], "Michal" => &[5], "Mikael" => &[5], "Michiel" => &[5], "Mihai" => &[5], "Mihail" => &[5], "Mihaly" => &[5], "Mikko" => &[5], "Micheal" => &[5],
+    "James" => &[6], "Jacques" => &[6], "Jaime" => &[6], "Jaume" => &[6], "Giacomo" => &[6], "Jacopo" => &[6], "Jacob" => &[6], "Jakob" => &[6], "Jakub" => &[6], "Jacobus" => &[6], "Iago" => &[6], "Seamus" => &[6], "Hamish" => &[6], "Yakov" => &[6],
+    "Joseph" => &[7], "Jose" => &[7], "Giuseppe" => &[7], "Josef" => &[7], "Jozef" => &[7], "Josep" => &[7], "Jozsef" => &[7], "Iosif" => &[7], "Osip" => &[7],
+    "William" => &[8], "Guillaume" => &[8], "Guillermo" => &[8], "Guglielmo" => &[8], "Guilherme" => &[8], "Wilhelm" => &[8], "Willem" => &[8], "Vilhelm" => &[8], "Vilem" => &[8], "Gwilym" => &[8],
+    "Charles" => &[9], "Carlos" => &[9], "Carlo" => &[9], "Carles" => &[9], "Karl" => &[9], "Carl" => &[9], "Karel" => &[9], "Karol" => &[9], "Karoly" => &[9], "Kaarle" => &[9],
+    "Henry" => &[10], "Henri" => &[10], "Enrique" => &[10], "Enrico" => &[10], "Enric" => &[10], "Henrique" => &[10], "Heinrich" => &[10], "Hendrik" => &[10], "Henrik" => &[10], "Henryk" => &[10], "Jindrich" => &[10],
+    "George" => &[11], "Georges" => &[11], "Jorge" => &[11], "Jordi" => &[11], "Giorgio" => &[11], "Georg" => &[11], "Georgios" => &[11], "Jerzy" => &[11], "Jiri" => &[11], "Yuri" => &[11], "Yury" => &[11], "Gyorgy" => &[11], "Joris" => &[11], "Jurgen" => &[11],
+    "Francis" => &[12], "Francois" => &[12], "Francisco" => &[12], "Francesco" => &[12], "Francesc" => &[12], "Francisc" => &[12], "Franz" => &[12], "Frans" => &[12], "Frantisek" => &[12], "Franciszek" => &[12], "Ferenc" => &[12],
+    "Anthony" => &[13], "Antony" => &[13], "Antoine" => &[13], "Antonio" => &[13], "Anton" => &[13], "Antoni" => &[13], "Antonin" => &[13], "Antonios" => &[13], "Antal" => &[13],
+    "Andrew" => &[14], "Andre" => &[14], "Andres" => &[14], "Andreas" => &[14], "Andrei" => &[14], "Andrey" => &[14], "Andrzej" => &[14], "Ondrej" => &[14], "Andras" => &[14], "Anders" => &[14], "Andreu" => &[14], "Andries" => &[14],
+    "Stephen" => &[15], "Steven" => &[15], "Stephane" => &[15], "Etienne" => &[15], "Esteban" => &[15], "Estevao" => &[15], "Esteve" => &[15], "Stefano" => &[15], "Stefan" => &[15], "Steffen" => &[15], "Stepan" => &[15], "Szczepan" => &[15], "Istvan" => &[15],
+    "Thomas" => &[16], "Tomas" => &[16], "Tommaso" => &[16], "Tomasz" => &[16], "Tamas" => &[16], "Tomaz" => &[16], "Tomos" => &[16], "Foma" => &[16],
+    "Matthew" => &[17], "Mathieu" => &[17], "Matthieu" => &[17], "Mateo" => &[17], "Matteo" => &[17], "Mateu" => &[17], "Matthias" => &[17], "Mathias" => &[17], "Mattias" => &[17], "Mateusz" => &[17], "Matej" => &[17], "Matvey" => &[17],
+    "Nicholas" => &[18], "Nicolas" => &[18], "Nicolo" => &[18], "Nicolau" => &[18], "Nikolai" => &[18], "Nikolay" => &[18], "Nikolaus" => &[18], "Nikolaos" => &[18], "Nikola" => &[18], "Niklas" => &[18], "Mikolaj" => &[18], "Miklos" => &[18],
+    "Alexander" => &[19], "Alexandre" => &[19], "Alejandro" => &[19], "Alessandro" => &[19], "Aleksander" => &[19], "Aleksandr" => &[19], "Alexandru" => &[19], "Alexandros" => &[19], "Sandor" => &[19],
+    "Mary" => &[20], "Marie" => &[20], "Maria" => &[20], "Mariya" => &[20], "Marija" => &[20], "Maire" => &[20],
+    "Catherine" => &[21], "Katherine" => &[21], "Kathryn" => &[21], "Caterina" => &[21], "Catarina" => &[21], "Catalina" => &[21], "Katharina" => &[21], "Katarzyna" => &[21], "Katerina" => &[21], "Ekaterina" => &[21], "Yekaterina" => &[21], "Katalin" => &[21], "Caitlin" => &[21], "Katrin" => &[21],
+    "Elizabeth" => &[22], "Elisabeth" => &[22], "Elisabet" => &[22], "Elisabetta" => &[22], "Elzbieta" => &[22], "Erzsebet" => &[22], "Elisaveta" => &[22], "Yelizaveta" => &[22], "Isabel" => &[22], "Isabelle" => &[22], "Isabella" => &[22],
+    "Margaret" => &[23], "Marguerite" => &[23], "Margarita" => &[23], "Margherita" => &[23], "Margarete" => &[23], "Margareta" => &[23], "Margriet" => &[23], "Malgorzata" => &[23], "Margit" => &[23], "Marketa" => &[23],
+    "Anne" => &[24], "Ann" => &[24], "Anna" => &[24], "Ana" => &[24],
+    "Helen" => &[25], "Helene" => &[25], "Helena" => &[25], "Elena" => &[25], "Eleni" => &[25], "Ellen" => &[25], "Yelena" => &[25], "Ilona" => &[25],
+    "Louis" => &[26], "Luis" => &[26], "Lluis" => &[26], "Luigi" => &[26], "Ludwig" => &[26], "Ludwik" => &[26], "Ludvig" => &[26], "Lodewijk" => &[26], "Ludovic" => &[26], "Ludovico" => &[26], "Lajos" => &[26],
+    "Lawrence" => &[27], "Laurence" => &[27], "Laurent" => &[27], "Lorenzo" => &[27], "Lorenz" => &[27], "Laurens" => &[27], "Lauri" => &[27], "Lars" => &[27], "Wawrzyniec" => &[27],
+    "Robert" => &[28], "Roberto" => &[28], "Robrecht" => &[28],
+    "Richard" => &[29], "Ricardo" => &[29], "Riccardo" => &[29], "Ryszard" => &[29], "Rikard" => &[29],
+    "Edward" => &[30], "Edouard" => &[30], "Eduardo" => &[30], "Edoardo" => &[30], "Eduard" => &[30], "Edvard" => &[30],
+    "Philip" => &[31], "Phillip" => &[31], "Philippe" => &[31], "Philipp" => &[31], "Felipe" => &[31], "Filipe" => &[31], "Filippo" => &[31], "Filip" => &[31], "Fulop" => &[31],
+    "Martin" => &[32], "Marcin" => &[32], "Martijn" => &[32],
+    "Christopher" => &[33], "Christophe" => &[33], "Christoph" => &[33], "Cristobal" => &[33], "Cristoforo" => &[33], "Cristovao" => &[33], "Krzysztof" => &[33], "Kristof" => &[33], "Kristoffer" => &[33],
+    "Gregory" => &[34], "Gregoire" => &[34], "Gregorio" => &[34], "Gregor" => &[34], "Grzegorz" => &[34], "Gergely" => &[34], "Grigori" => &[34], "Grigory" => &[34],
+    "Theresa" => &[35], "Teresa" => &[35], "Therese" => &[35], "Tereza" => &[35],
+    "Susan" => &[36], "Suzanne" => &[36], "Susanne" => &[36], "Susanna" => &[36], "Susana" => &[36], "Zuzana" => &[36], "Zsuzsanna" => &[36],
+    "Frederick" => &[37], "Frederic" => &[37], "Federico" => &[37], "Friedrich" => &[37], "Frederik" => &[37], "Fryderyk" => &[37], "Bedrich" => &[37], "Frigyes" => &[37],
+    "Vincent" => &[38], "Vicente" => &[38], "Vincenzo" => &[38], "Wincenty" => &[38],
+    "Eugene" => &[39], "Eugenio" => &[39], "Eugen" => &[39], "Eugeniusz" => &[39], "Evgeny" => &[39], "Yevgeny" => &[39], "Jeno" => &[39],
+    "Patrick" => &[40], "Patrice" => &[40], "Patricio" => &[40], "Patrizio" => &[40], "Padraig" => &[40], "Patryk" => &[40],
+    "Benedict" => &[41], "Benoit" => &[41], "Benito" => &[41], "Benedetto" => &[41], "Benedikt" => &[41], "Benedykt" => &[41],
+    "Bartholomew" => &[42], "Barthelemy" => &[42], "Bartolome" => &[42], "Bartolomeo" => &[42], "Bartlomiej" => &[42],
+};
@@ -1,22 +1,241 @@
-use super::nickname::have_matching_variants;
+use super::nickname::{have_matching_variants, matching_variant_kind, NicknameDb, VariantKind};
+use super::patronymic::is_patronymic_particle;
+use super::phonetic::sounds_alike;
 use super::utils::*;
 use super::{Name, Words};
 use std::borrow::Cow;
+use std::cmp;
 use std::iter::{Enumerate, Peekable};
+use std::mem;
 use std::ops::Range;
 use std::slice::Iter;
 use std::str::Chars;
+use std::usize;
 use unicode_segmentation::UnicodeSegmentation;
 
 pub const MIN_SURNAME_CHAR_MATCH: usize = 4;
 pub const MIN_GIVEN_NAME_CHAR_MATCH: usize = 3;
 
+// Weights of each component in `similarity`, which sum to one
+const GIVEN_NAME_WEIGHT: f32 = 0.45;
+const SURNAME_WEIGHT: f32 = 0.45;
+const MIDDLE_NAMES_WEIGHT: f32 = 0.05;
+const SUFFIX_WEIGHT: f32 = 0.05;
+
+/// Options controlling how strictly `Name::consistent_with_opts` matches
+/// names.
+///
+/// The default is the behavior of `consistent_with`, which errs on the side
+/// of false positives; `MatchOptions::strict()` errs the other way.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub struct MatchOptions<'a> {
+    /// Allow a given or middle name to match a prefix of the other, as in
+    /// "Dan" and "Daniel".
+    pub allow_given_name_prefixes: bool,
+    /// Allow given or middle names to match by our nickname tables and
+    /// patterns, as in "Bill" and "William" or "Kenny" and "Kenneth".
+    /// Nicknames given explicitly with a name, as in "William (Bill) Gates",
+    /// are matched either way.
+    pub allow_nicknames: bool,
+    /// Require suffixes to be equal, rather than only to match when both
+    /// names have one.
+    pub require_equal_suffixes: bool,
+    /// The number of letters which must match when one surname is only part
+    /// of the other, as in "Gayo" and "del Río Gayo". Values below
+    /// `MIN_SURNAME_CHAR_MATCH` have no effect.
+    pub min_surname_char_match: usize,
+    /// Allow surnames to differ by a single typo (an added, missing or wrong
+    /// letter, or two adjacent letters swapped), as in "James Hanson" and
+    /// "James Hansen", when the given names are identical. Such names have
+    /// different `hash`es, so use `surname_typo_hashes` to find candidates.
+    pub allow_surname_typos: bool,
+    /// Allow surnames which sound alike by Double Metaphone, as in "Schmidt"
+    /// and "Smith". Such names may have different `hash`es, so use the
+    /// `metaphone` encodings from `phonetic_keys` to find candidates.
+    pub allow_phonetic_surnames: bool,
+    /// Allow given or middle names which are equivalents in different
+    /// European languages, as in "John", "Juan" and "Giovanni". Like
+    /// nicknames, those with different initials only match when neither
+    /// name has middle names or initials.
+    pub allow_cognates: bool,
+    /// Nicknames to match given names by, with `allow_nicknames`, in
+    /// addition to or instead of our built-in tables (see `NicknameDb`).
+    pub nicknames: Option<&'a NicknameDb>,
+}
+
+impl<'a> MatchOptions<'a> {
+    /// Matches given names only when identical or initials, suffixes only
+    /// when equal, and surnames only when identical.
+    pub fn strict() -> MatchOptions<'a> {
+        MatchOptions {
+            allow_given_name_prefixes: false,
+            allow_nicknames: false,
+            require_equal_suffixes: true,
+            min_surname_char_match: usize::MAX,
+            allow_surname_typos: false,
+            allow_phonetic_surnames: false,
+            allow_cognates: false,
+            nicknames: None,
+        }
+    }
+
+    // Shorter partial matches would break the guarantee that consistent
+    // names share a `hash`
+    fn min_surname_char_match(&self) -> usize {
+        cmp::max(MIN_SURNAME_CHAR_MATCH, self.min_surname_char_match)
+    }
+}
+
+impl<'a> Default for MatchOptions<'a> {
+    fn default() -> MatchOptions<'a> {
+        MatchOptions {
+            allow_given_name_prefixes: true,
+            allow_nicknames: true,
+            require_equal_suffixes: false,
+            min_surname_char_match: MIN_SURNAME_CHAR_MATCH,
+            allow_surname_typos: false,
+            allow_phonetic_surnames: false,
+            allow_cognates: false,
+            nicknames: None,
+        }
+    }
+}
+
+/// How strongly two names suggest the same person, from 0 (inconsistent)
+/// to 1 (all components present and identical).
+///
+/// Returned by `Name::similarity`.
+#[derive(Clone, Copy, Debug, PartialEq, PartialOrd)]
+pub struct MatchScore(pub f32);
+
+impl MatchScore {
+    /// The score of names which are not consistent.
+    pub const NONE: MatchScore = MatchScore(0.0);
+
+    /// Whether the names are consistent at all.
+    pub fn is_match(self) -> bool {
+        self.0 > 0.0
+    }
+}
+
+/// The outcome of comparing one component of two names, or one pair of
+/// aligned words or initials.
+#[derive(Clone, Copy, Debug, Eq, PartialEq, Hash)]
+pub enum Outcome {
+    /// Identical, ignoring case, accents and transliteration.
+    Exact,
+    /// One is a known nickname of the other (e.g. "Bill" and "William"),
+    /// either from our nickname tables or given explicitly with the name.
+    Nickname,
+    /// One is a prefix of the other (e.g. "Dave" and "David").
+    Prefix,
+    /// One is a diminutive of the other (e.g. "Kenny" and "Kenneth").
+    Diminutive,
+    /// One is the final syllables of the other (e.g. "Beth" and "Elizabeth").
+    FinalSyllables,
+    /// Equivalents in different languages (e.g. "John" and "Juan"), with
+    /// `MatchOptions::allow_cognates`.
+    Cognate,
+    /// A surname is part of the other (e.g. "Gayo" and "del Río Gayo").
+    Partial,
+    /// Only initials could be compared.
+    InitialsOnly,
+    /// Present in only one of the names, or neither.
+    Missing,
+    /// Inconsistent.
+    Conflict,
+}
+
+impl Outcome {
+    // As a fraction of the weight of the component it applies to, for
+    // `similarity`
+    fn strength(self) -> f32 {
+        match self {
+            Outcome::Exact => 1.0,
+            Outcome::Nickname | Outcome::Diminutive => 0.8,
+            Outcome::FinalSyllables | Outcome::Cognate => 0.7,
+            Outcome::Prefix | Outcome::Partial => 0.6,
+            Outcome::InitialsOnly => 0.5,
+            Outcome::Missing => 0.3,
+            Outcome::Conflict => 0.0,
+        }
+    }
+}
+
+impl From<VariantKind> for Outcome {
+    fn from(kind: VariantKind) -> Outcome {
+        match kind {
+            VariantKind::Nickname => Outcome::Nickname,
+            VariantKind::Prefix => Outcome::Prefix,
+            VariantKind::Diminutive => Outcome::Diminutive,
+            VariantKind::FinalSyllables => Outcome::FinalSyllables,
+            VariantKind::Cognate => Outcome::Cognate,
+        }
+    }
+}
+
+/// Two words or initials aligned with each other by `compare_explain`.
+#[derive(Clone, Debug, Eq, PartialEq, Hash)]
+pub struct AlignedPair {
+    pub mine: String,
+    pub theirs: String,
+    pub outcome: Outcome,
+}
+
+/// The outcome of comparing one component of two names, e.g. the surname.
+#[derive(Clone, Debug, Eq, PartialEq, Hash)]
+pub struct ComponentReport {
+    pub outcome: Outcome,
+    /// The words or initials which were compared, in order.
+    pub aligned: Vec<AlignedPair>,
+}
+
+impl ComponentReport {
+    fn for_pair(mine: String, theirs: String, consistent: bool) -> ComponentReport {
+        let outcome = if consistent {
+            Outcome::Exact
+        } else {
+            Outcome::Conflict
+        };
+
+        ComponentReport {
+            outcome,
+            aligned: vec![AlignedPair {
+                mine,
+                theirs,
+                outcome,
+            }],
+        }
+    }
+
+    fn missing() -> ComponentReport {
+        ComponentReport {
+            outcome: Outcome::Missing,
+            aligned: Vec::new(),
+        }
+    }
+}
+
+/// Why two names are or are not consistent.
+///
+/// Returned by `Name::compare_explain`.
+#[derive(Clone, Debug, Eq, PartialEq, Hash)]
+pub struct ComparisonReport {
+    /// The same as `consistent_with`.
+    pub consistent: bool,
+    /// Given and middle names and initials.
+    pub given_names: ComponentReport,
+    pub surname: ComponentReport,
+    pub patronymic: ComponentReport,
+    pub suffix: ComponentReport,
+}
+
 impl Name {
     /// Might this name represent the same person as another name?
     ///
     /// # Examples
     /// ```
-    /// use human_name::Name;
+    /// use human_name::{Name, ParseOptions};
     ///
     /// let j_doe = Name::parse("J. Doe").unwrap();
     /// let jane_doe = Name::parse("Jane Doe").unwrap();
@@ -33,6 +252,26 @@ impl Name {
     /// let zheng_he = Name::parse("Zheng He").unwrap();
     /// let han_chars = Name::parse("鄭和").unwrap();
     /// assert!(han_chars.consistent_with(&zheng_he));
+    ///
+    /// let robert = Name::parse("Robert 'Skip' Smith").unwrap();
+    /// let skip = Name::parse("Skip Smith").unwrap();
+    /// assert!(robert.consistent_with(&skip));
+    ///
+    /// let options = ParseOptions {
+    ///     iberian_surnames: true,
+    ///     ..Default::default()
+    /// };
+    /// let juan_perez = Name::parse_with_options("Juan Perez", &options).unwrap();
+    /// let juan_perez_gonzalez = Name::parse_with_options("Juan Perez Gonzalez", &options).unwrap();
+    /// let juan_perez_lopez = Name::parse_with_options("Juan Perez Lopez", &options).unwrap();
+    /// assert!(juan_perez.consistent_with(&juan_perez_gonzalez));
+    /// assert!(!juan_perez_lopez.consistent_with(&juan_perez_gonzalez));
+    ///
+    /// let ivan_ivanovich = Name::parse("Ivan Ivanovich Petrov").unwrap();
+    /// let ivan_sergeyevich = Name::parse("Ivan Sergeyevich Petrov").unwrap();
+    /// let i_petrov = Name::parse("I. Petrov").unwrap();
+    /// assert!(ivan_ivanovich.consistent_with(&i_petrov));
+    /// assert!(!ivan_ivanovich.consistent_with(&ivan_sergeyevich));
     /// ```
     ///
     /// # Defining "consistency"
@@ -49,55 +288,459 @@ impl Name {
     /// In the case of given and middle names, allows one name to be a prefix of
     /// the other, without requiring the prefix end at a word boundary as we do
     /// with surname suffix matches, and supports matching a small number of
-    /// common nicknames and nickname patterns based on the root name.
+    /// common nicknames and nickname patterns based on the root name. Also
+    /// allows the given name of one to match a nickname explicitly given with
+    /// the other (as in "William (Bill) Gates"), in place of its given name
+    /// only: their middle names and initials must still be consistent.
+    ///
+    /// For names parsed with the `iberian_surnames` option, the paternal
+    /// surnames must match, but the maternal surnames need only match if both
+    /// are present, so "Juan Pérez" is consistent with "Juan Pérez González".
+    ///
+    /// Patronymics (see `patronymic`) are not treated as middle names, and
+    /// may be missing from either name, so "Ivan Ivanovich Petrov" is
+    /// consistent with "I. Petrov" but not "Ivan Sergeyevich Petrov" (or
+    /// "I. S. Petrov", where the other name has an initial in its place).
     ///
     /// # Limitations
     ///
     /// There will be false positives ("Jan Doe" is probably not "Jane Doe"),
-    /// and false negatives ("James Hanson" might be "James Hansen"). And, of
-    /// course, even identical names do not necessarily represent the same person.
+    /// and false negatives ("James Hanson" might be "James Hansen", although
+    /// see `MatchOptions::allow_surname_typos`). And, of course, even identical
+    /// names do not necessarily represent the same person.
     ///
     /// Given limited information, we err on the side of false positives. This
     /// kind of matching will be most useful in cases where we already have
     /// reason to believe that a single individual's name appears twice, and we
     /// are trying to figure out exactly where, e.g. a particular author's index
-    /// in the list of authors of a co-authored paper.
+    /// in the list of authors of a co-authored paper. For stricter matching,
+    /// see `consistent_with_opts`.
     ///
-    #[rustfmt::skip]
     pub fn consistent_with(&self, other: &Name) -> bool {
+        self.consistent_with_opts(other, &MatchOptions::default())
+    }
+
+    /// Like `consistent_with`, but allows turning off some of the looser
+    /// matching rules.
+    ///
+    /// ```
+    /// use human_name::{MatchOptions, Name};
+    ///
+    /// let dan_smith = Name::parse("Dan Smith").unwrap();
+    /// let daniel_smith = Name::parse("Daniel Smith").unwrap();
+    /// let daniel_smith_jr = Name::parse("Daniel Smith, Jr.").unwrap();
+    /// assert!(dan_smith.consistent_with(&daniel_smith));
+    /// assert!(daniel_smith.consistent_with(&daniel_smith_jr));
+    ///
+    /// let strict = MatchOptions::strict();
+    /// assert!(!dan_smith.consistent_with_opts(&daniel_smith, &strict));
+    /// assert!(!daniel_smith.consistent_with_opts(&daniel_smith_jr, &strict));
+    ///
+    /// let no_prefixes = MatchOptions {
+    ///     allow_given_name_prefixes: false,
+    ///     ..Default::default()
+    /// };
+    /// let bill_smith = Name::parse("Bill Smith").unwrap();
+    /// let william_smith = Name::parse("William Smith").unwrap();
+    /// assert!(!dan_smith.consistent_with_opts(&daniel_smith, &no_prefixes));
+    /// assert!(bill_smith.consistent_with_opts(&william_smith, &no_prefixes));
+    ///
+    /// let typos = MatchOptions {
+    ///     allow_surname_typos: true,
+    ///     ..Default::default()
+    /// };
+    /// let james_hanson = Name::parse("James Hanson").unwrap();
+    /// let james_hansen = Name::parse("James Hansen").unwrap();
+    /// let j_hansen = Name::parse("J. Hansen").unwrap();
+    /// assert!(!james_hanson.consistent_with(&james_hansen));
+    /// assert!(james_hanson.consistent_with_opts(&james_hansen, &typos));
+    /// assert!(!james_hanson.consistent_with_opts(&j_hansen, &typos));
+    ///
+    /// // Too few letters to match only part of a surname, whatever the option
+    /// let short_partial = MatchOptions {
+    ///     min_surname_char_match: 2,
+    ///     ..typos
+    /// };
+    /// let jane_rua = Name::parse("Jane Rua").unwrap();
+    /// let jane_de_la_rua = Name::parse("Jane de la Rua").unwrap();
+    /// assert!(!jane_rua.consistent_with_opts(&jane_de_la_rua, &short_partial));
+    ///
+    /// let phonetic = MatchOptions {
+    ///     allow_phonetic_surnames: true,
+    ///     ..Default::default()
+    /// };
+    /// let john_schmidt = Name::parse("John Schmidt").unwrap();
+    /// let john_smith = Name::parse("John Smith").unwrap();
+    /// let jane_smith = Name::parse("Jane Smith").unwrap();
+    /// assert!(john_schmidt.consistent_with_opts(&john_smith, &phonetic));
+    /// assert!(!john_schmidt.consistent_with_opts(&jane_smith, &phonetic));
+    ///
+    /// let cognates = MatchOptions {
+    ///     allow_cognates: true,
+    ///     ..Default::default()
+    /// };
+    /// let giovanni_rossi = Name::parse("Giovanni Rossi").unwrap();
+    /// let john_rossi = Name::parse("John Rossi").unwrap();
+    /// let juan_a_rossi = Name::parse("Juan A. Rossi").unwrap();
+    /// assert!(!giovanni_rossi.consistent_with(&john_rossi));
+    /// assert!(giovanni_rossi.consistent_with_opts(&john_rossi, &cognates));
+    /// assert!(john_rossi.consistent_with_opts(&juan_a_rossi, &cognates));
+    /// ```
+    #[rustfmt::skip]
+    pub fn consistent_with_opts(&self, other: &Name, options: &MatchOptions) -> bool {
         // Fast path
-        if self.hash != other.hash {
+        let any_surname_hash = options.allow_surname_typos || options.allow_phonetic_surnames;
+        if self.hash != other.hash && !any_surname_hash {
             return false;
         }
 
         // Check given name(s) first because if we got this far, we know that
-        // at least the last characters of the surnames are consistent
-        self.given_and_middle_names_consistent(other) &&
-        self.surname_consistent(other) &&
-        self.suffix_consistent(other)
+        // at least the last characters of the surnames are consistent (unless
+        // allowing typos or phonetic matches)
+        (self.given_and_middle_names_consistent(other, options, None) ||
+         self.given_and_middle_names_consistent_by_nickname(other, options)) &&
+        (self.surname_consistent(other, options) ||
+         self.surname_typo_consistent(other, options) ||
+         (options.allow_phonetic_surnames && sounds_alike(&self.surname(), &other.surname()))) &&
+        self.patronymic_consistent(other) &&
+        self.suffix_consistent(other, options)
     }
 
-    fn given_and_middle_names_consistent(&self, other: &Name) -> bool {
+    /// Grades how strongly this name and another suggest the same person,
+    /// for ranking candidate matches.
+    ///
+    /// Inconsistent names (see `consistent_with`) always score zero. Otherwise,
+    /// the score is highest when given names and surnames are identical, lower
+    /// for nicknames, lower still for prefixes (as in "Dan" and "Daniel") and
+    /// surnames matching only in part (as in "Gayo" and "del Río Gayo"), and
+    /// lowest when only initials are known. Agreement in middle names and
+    /// suffixes counts slightly in favor, and having them on only one side
+    /// slightly against.
+    ///
+    /// ```
+    /// use human_name::Name;
+    ///
+    /// let jane_doe = Name::parse("Jane Doe").unwrap();
+    /// let jane_m_doe = Name::parse("Jane M. Doe").unwrap();
+    /// let janie_doe = Name::parse("Janie Doe").unwrap();
+    /// let j_doe = Name::parse("J. Doe").unwrap();
+    /// let john_doe = Name::parse("John Doe").unwrap();
+    ///
+    /// assert_eq!(1.0, jane_doe.similarity(&jane_doe).0);
+    /// assert!(jane_doe.similarity(&jane_m_doe) > jane_doe.similarity(&janie_doe));
+    /// assert!(jane_doe.similarity(&janie_doe) > jane_doe.similarity(&j_doe));
+    /// assert!(jane_doe.similarity(&j_doe).is_match());
+    /// assert!(!jane_doe.similarity(&john_doe).is_match());
+    /// ```
+    pub fn similarity(&self, other: &Name) -> MatchScore {
+        if !self.consistent_with(other) {
+            return MatchScore::NONE;
+        }
+
+        let middle_names = match (self.middle_initials(), other.middle_initials()) {
+            (Some(_), Some(_)) | (None, None) => Outcome::Exact,
+            _ => Outcome::Missing,
+        };
+
+        let suffix = match (self.generation_from_suffix, other.generation_from_suffix) {
+            (Some(_), Some(_)) | (None, None) => Outcome::Exact,
+            _ => Outcome::Missing,
+        };
+
+        MatchScore(
+            GIVEN_NAME_WEIGHT
+                * self
+                    .given_name_outcome(other, &MatchOptions::default())
+                    .strength()
+                + SURNAME_WEIGHT * self.surname_outcome(other).strength()
+                + MIDDLE_NAMES_WEIGHT * middle_names.strength()
+                + SUFFIX_WEIGHT * suffix.strength(),
+        )
+    }
+
+    /// Compares this name with another like `consistent_with`, but reports
+    /// the outcome for each component of the names, and which of their words
+    /// or initials were compared with each other.
+    ///
+    /// ```
+    /// use human_name::{Name, Outcome};
+    ///
+    /// let a = Name::parse("Kenneth J. Smith III").unwrap();
+    /// let b = Name::parse("Kenny John Smith Jr.").unwrap();
+    ///
+    /// let report = a.compare_explain(&b);
+    /// assert!(!report.consistent);
+    /// assert_eq!(Outcome::Diminutive, report.given_names.outcome);
+    /// assert_eq!("Kenneth", report.given_names.aligned[0].mine);
+    /// assert_eq!("Kenny", report.given_names.aligned[0].theirs);
+    /// assert_eq!(Outcome::InitialsOnly, report.given_names.aligned[1].outcome);
+    /// assert_eq!(Outcome::Exact, report.surname.outcome);
+    /// assert_eq!(Outcome::Missing, report.patronymic.outcome);
+    /// assert_eq!(Outcome::Conflict, report.suffix.outcome);
+    ///
+    /// let ivan = Name::parse("Ivan Ivanovich Petrov").unwrap();
+    /// let report = ivan.compare_explain(&Name::parse("I. S. Petrov").unwrap());
+    /// assert!(!report.consistent);
+    /// assert_eq!(Outcome::Conflict, report.patronymic.outcome);
+    /// ```
+    pub fn compare_explain(&self, other: &Name) -> ComparisonReport {
+        self.compare_explain_opts(other, &MatchOptions::default())
+    }
+
+    /// Like `compare_explain`, but compares the names like
+    /// `consistent_with_opts` with the given options.
+    ///
+    /// ```
+    /// use human_name::{MatchOptions, Name, Outcome};
+    ///
+    /// let a = Name::parse("James Hanson").unwrap();
+    /// let b = Name::parse("James Hansen").unwrap();
+    /// let typos = MatchOptions {
+    ///     allow_surname_typos: true,
+    ///     ..Default::default()
+    /// };
+    ///
+    /// assert_eq!(Outcome::Conflict, a.compare_explain(&b).surname.outcome);
+    ///
+    /// let report = a.compare_explain_opts(&b, &typos);
+    /// assert!(report.consistent);
+    /// assert_eq!(Outcome::Partial, report.surname.outcome);
+    /// ```
+    pub fn compare_explain_opts(&self, other: &Name, options: &MatchOptions) -> ComparisonReport {
+        let mut aligned = Vec::new();
+        let given_names_consistent =
+            self.given_and_middle_names_consistent(other, options, Some(&mut aligned))
+                || self.given_and_middle_names_consistent_by_nickname(other, options);
+        let given_names = ComponentReport {
+            outcome: if given_names_consistent {
+                self.given_name_outcome(other, options)
+            } else {
+                Outcome::Conflict
+            },
+            aligned,
+        };
+
+        let surname_outcome = if self.hash == other.hash && self.surname_consistent(other, options)
+        {
+            self.surname_outcome(other)
+        } else if self.surname_typo_consistent(other, options)
+            || (options.allow_phonetic_surnames && sounds_alike(&self.surname(), &other.surname()))
+        {
+            Outcome::Partial
+        } else {
+            Outcome::Conflict
+        };
+        let surname = ComponentReport {
+            outcome: surname_outcome,
+            aligned: vec![AlignedPair {
+                mine: self.surname().into_owned(),
+                theirs: other.surname().into_owned(),
+                outcome: surname_outcome,
+            }],
+        };
+
+        let patronymic = match (self.patronymic(), other.patronymic()) {
+            (Some(mine), Some(theirs)) => ComponentReport::for_pair(
+                mine.into_owned(),
+                theirs.into_owned(),
+                self.patronymic_consistent(other),
+            ),
+            _ if !self.patronymic_consistent(other) => ComponentReport {
+                outcome: Outcome::Conflict,
+                aligned: Vec::new(),
+            },
+            _ => ComponentReport::missing(),
+        };
+
+        let suffix = match (self.suffix(), other.suffix()) {
+            (Some(mine), Some(theirs)) => ComponentReport::for_pair(
+                mine.to_string(),
+                theirs.to_string(),
+                self.suffix_consistent(other, options),
+            ),
+            _ => ComponentReport::missing(),
+        };
+
+        let consistent = given_names_consistent
+            && surname.outcome != Outcome::Conflict
+            && patronymic.outcome != Outcome::Conflict
+            && suffix.outcome != Outcome::Conflict;
+
+        ComparisonReport {
+            consistent,
+            given_names,
+            surname,
+            patronymic,
+            suffix,
+        }
+    }
+
+    // Assumes the given and middle names are consistent
+    fn given_name_outcome(&self, other: &Name, options: &MatchOptions) -> Outcome {
+        let (mine, theirs) = match (self.given_name(), other.given_name()) {
+            (Some(mine), Some(theirs)) => (mine, theirs),
+            _ => return Outcome::InitialsOnly,
+        };
+
+        if transliterated_lowercase(mine) == transliterated_lowercase(theirs) {
+            Outcome::Exact
+        } else if self.nickname_consistent(other) {
+            Outcome::Nickname
+        } else if let Some(kind) = matching_variant_kind(mine, theirs, options) {
+            kind.into()
+        } else {
+            // Consistent by way of middle names or initials
+            Outcome::InitialsOnly
+        }
+    }
+
+    // Assumes the surnames are consistent
+    fn surname_outcome(&self, other: &Name) -> Outcome {
+        if transliterated_lowercase(&self.surname()) == transliterated_lowercase(&other.surname()) {
+            Outcome::Exact
+        } else {
+            Outcome::Partial
+        }
+    }
+
+    fn nickname_consistent(&self, other: &Name) -> bool {
+        fn nickname_matches_given_name(a: &Name, b: &Name) -> bool {
+            match (a.nickname(), b.given_name()) {
+                (Some(nickname), Some(given_name)) => nickname
+                    .chars()
+                    .flat_map(transliterate)
+                    .filter_map(lowercase_if_alpha)
+                    .eq(given_name
+                        .chars()
+                        .flat_map(transliterate)
+                        .filter_map(lowercase_if_alpha)),
+                _ => false,
+            }
+        }
+
+        nickname_matches_given_name(self, other) || nickname_matches_given_name(other, self)
+    }
+
+    // Whether the given and middle names are consistent once a nickname given
+    // with one name stands in for its given name, where the nickname matches
+    // the given name of the other (so "William (Bill) Alan Gates" is
+    // consistent with "Bill A. Gates", but not with "Bill Zachary Gates")
+    fn given_and_middle_names_consistent_by_nickname(
+        &self,
+        other: &Name,
+        options: &MatchOptions,
+    ) -> bool {
+        fn consistent_by_nickname(a: &Name, b: &Name, options: &MatchOptions) -> bool {
+            match (a.nickname(), b.given_name()) {
+                (Some(nickname), Some(given_name))
+                    if transliterated_lowercase(nickname)
+                        == transliterated_lowercase(given_name) => {}
+                _ => return false,
+            }
+
+            // Compare the rest of our given names with whatever follows their
+            // given name, as if our nickname had been our given name
+            let mut mine = a.given_names_or_initials();
+            let my_skipped = mine.next().map_or(0, |part| part.initials_count() as usize);
+
+            let mut theirs = b.given_names_or_initials();
+            let mut their_skipped = 0;
+            for part in &mut theirs {
+                their_skipped += part.initials_count() as usize;
+                if part.has_word() {
+                    break;
+                }
+            }
+
+            let my_initials = a.transliterated_initials_after(my_skipped);
+            let their_initials = b.transliterated_initials_after(their_skipped);
+            let missing_any_names = a.missing_any_name() || b.missing_any_name();
+
+            if my_initials.len() >= their_initials.len() {
+                my_initials.contains(&*their_initials)
+                    && parts_consistent(mine, theirs, options, missing_any_names, None)
+            } else {
+                their_initials.contains(&*my_initials)
+                    && parts_consistent(theirs, mine, options, missing_any_names, None)
+            }
+        }
+
+        consistent_by_nickname(self, other, options) || consistent_by_nickname(other, self, options)
+    }
+
+    // When `aligned` is given, records the words or initials compared
+    fn given_and_middle_names_consistent(
+        &self,
+        other: &Name,
+        options: &MatchOptions,
+        mut aligned: Option<&mut Vec<AlignedPair>>,
+    ) -> bool {
         // Handle simple cases first, where we only have to worry about one name
         // and/or initial.
         if self.middle_initials().is_none() && other.middle_initials().is_none() {
             if self.given_name().is_none() || other.given_name().is_none() {
-                return to_ascii_letter(self.first_initial())
-                    == to_ascii_letter(other.first_initial());
+                let consistent =
+                    to_ascii_letter(self.first_initial()) == to_ascii_letter(other.first_initial());
+
+                if let Some(aligned) = aligned {
+                    aligned.push(AlignedPair {
+                        mine: self.first_initial().to_string(),
+                        theirs: other.first_initial().to_string(),
+                        outcome: if consistent {
+                            Outcome::InitialsOnly
+                        } else {
+                            Outcome::Conflict
+                        },
+                    });
+                }
+
+                return consistent;
+            } else if let Some(aligned) = aligned {
+                let mine = self.given_name().unwrap();
+                let theirs = other.given_name().unwrap();
+                let outcome = if transliterated_lowercase(mine) == transliterated_lowercase(theirs)
+                {
+                    Outcome::Exact
+                } else {
+                    matching_variant_kind(mine, theirs, options)
+                        .map_or(Outcome::Conflict, Outcome::from)
+                };
+
+                aligned.push(AlignedPair {
+                    mine: mine.to_string(),
+                    theirs: theirs.to_string(),
+                    outcome,
+                });
+
+                return outcome != Outcome::Conflict;
             } else {
-                return have_matching_variants(
-                    self.given_name().unwrap(),
-                    other.given_name().unwrap(),
-                );
+                let mine = self.given_name().unwrap();
+                let theirs = other.given_name().unwrap();
+                return transliterated_lowercase(mine) == transliterated_lowercase(theirs)
+                    || have_matching_variants(mine, theirs, options);
             }
         }
 
         // For the more complicated cases, we'll simplify things a bit by
         // letting ourselves assume `self` has the more complete name.
         if self.initials().chars().count() >= other.initials().chars().count() {
-            self.given_and_middle_names_consistent_with_less_complete(other)
+            self.given_and_middle_names_consistent_with_less_complete(other, options, aligned)
         } else {
-            other.given_and_middle_names_consistent_with_less_complete(self)
+            let start = aligned.as_ref().map_or(0, |aligned| aligned.len());
+            let consistent = other.given_and_middle_names_consistent_with_less_complete(
+                self,
+                options,
+                aligned.as_mut().map(|aligned| &mut **aligned),
+            );
+
+            // Put the pairs back in our point of view
+            if let Some(aligned) = aligned {
+                for pair in &mut aligned[start..] {
+                    mem::swap(&mut pair.mine, &mut pair.theirs);
+                }
+            }
+
+            consistent
         }
     }
 
@@ -109,7 +752,12 @@ impl Name {
         }
     }
 
-    fn given_and_middle_names_consistent_with_less_complete(&self, other: &Name) -> bool {
+    fn given_and_middle_names_consistent_with_less_complete(
+        &self,
+        other: &Name,
+        options: &MatchOptions,
+        aligned: Option<&mut Vec<AlignedPair>>,
+    ) -> bool {
         // Check initials first
         if !self.initials_consistent_with_less_complete(other) {
             return false;
@@ -128,85 +776,13 @@ impl Name {
 
         let missing_any_names = self.missing_any_name() || other.missing_any_name();
 
-        let mut their_parts = other.given_names_or_initials().peekable();
-        let mut suffix_for_prior_prefix_match: Option<String> = None;
-        let mut looked_up_nicknames = false;
-
-        let mut their_part_if_any = their_parts.next();
-
-        for my_part in self.given_names_or_initials() {
-            if let Some(ref their_part) = their_part_if_any {
-                let result = my_part.check_consistency(their_part, !looked_up_nicknames);
-
-                match result {
-                    ComparisonResult::Inconsistent => {
-                        // The names are inconsistent
-                        return false;
-                    }
-                    ComparisonResult::DifferentInitials => {
-                        // They don't have a word for this initial, so we don't
-                        // advance the iterator for their words/initials
-                        continue;
-                    }
-                    ComparisonResult::NicknameMatch => {
-                        looked_up_nicknames = true;
-                    }
-                    ComparisonResult::PrefixOfOther(remaining_chars) => {
-                        suffix_for_prior_prefix_match = Some(remaining_chars);
-                    }
-                    _ => {
-                        // Any other kind of match; no-op, just continue
-                    }
-                }
-            } else if missing_any_names {
-                // We've matched everything available, and will skip the check
-                // in the next block
-                return true;
-            } else if let Some(suffix) = suffix_for_prior_prefix_match {
-                // We've matched everything available, but we're not quite done.
-                //
-                // It's not uncommon for representations of a name to be inconsistent
-                // in whether two parts of a given name are separated by a space,
-                // a hyphen, or nothing (especially with transliterated names).
-                //
-                // This is one of the reasons we accept prefix-only matches for
-                // given & middle names. However, doing so potentially opens us up
-                // to more false positives, and we want to mitigate that.
-                //
-                // When it looks like we have a name part following a space or hyphen
-                // which might reasonably be treated as part of the same actual name
-                // as the preceding part, we don't want to just accept a prefix-only
-                // match on the preceding part as sufficient for a full match.
-                // Instead we'll continue after the prefix match by comparing the
-                // following part to the suffix, just as if the two parts hadn't
-                // been separated by a space or hyphen.
-                //
-                // This separates, e.g., "Jinli" from "Jin Yi", or "Xiaofeng" from
-                // "Xiao Peng".
-                //
-                // We don't try to do this in the presence of middle initials
-                // without corresponding names, because the logic would have to
-                // be even more complicated.
-                if let NameWordOrInitial::Word(word, _) = my_part {
-                    return eq_or_starts_with!(suffix, word);
-                } else {
-                    return true;
-                }
-            } else {
-                // We've matched everything available
-                return true;
-            }
-
-            let mut advance_by = my_part.initials_count();
-            while advance_by > 0 && their_part_if_any.is_some() {
-                their_part_if_any = their_parts.next();
-                if let Some(ref their_part) = their_part_if_any {
-                    advance_by -= their_part.initials_count();
-                }
-            }
-        }
-
-        their_part_if_any.is_none()
+        parts_consistent(
+            self.given_names_or_initials(),
+            other.given_names_or_initials(),
+            options,
+            missing_any_names,
+            aligned,
+        )
     }
 
     fn initials_consistent_with_less_complete(&self, other: &Name) -> bool {
@@ -253,12 +829,18 @@ impl Name {
     }
 
     fn transliterated_initials(&self) -> Cow<str> {
-        if self.initials().is_ascii() {
-            Cow::Borrowed(self.initials())
+        self.transliterated_initials_after(0)
+    }
+
+    fn transliterated_initials_after<'a>(&'a self, skipped: usize) -> Cow<'a, str> {
+        let initials = self.initials();
+        if initials.is_ascii() {
+            Cow::Borrowed(&initials[skipped.min(initials.len())..])
         } else {
             Cow::Owned(
-                self.initials()
+                initials
                     .chars()
+                    .skip(skipped)
                     .filter_map(to_ascii_letter)
                     .collect::<String>(),
             )
@@ -284,95 +866,65 @@ impl Name {
             }
         }
 
-        self.surname_index > prev
+        self.given_names_end() > prev
     }
 
-    fn surname_consistent(&self, other: &Name) -> bool {
+    fn surname_consistent(&self, other: &Name, options: &MatchOptions) -> bool {
+        let min_char_match = options.min_surname_char_match();
+
+        if self.iberian_surnames || other.iberian_surnames {
+            return self.iberian_surnames_consistent(other, min_char_match);
+        }
+
         // Fast path
         if self.simple_surname() && other.simple_surname() {
             return self.surname().eq_ignore_ascii_case(&*other.surname());
         }
 
-        let mut my_words = self.surname_iter().flat_map(|w| w.unicode_words()).rev();
-
-        let mut their_words = other.surname_iter().flat_map(|w| w.unicode_words()).rev();
+        surname_words_consistent(self.surname_iter(), other.surname_iter(), min_char_match)
+    }
 
-        let mut my_word = my_words.next();
-        let mut their_word = their_words.next();
-        let mut matching_chars = 0;
+    // Paternal surnames must match, as must maternal surnames if both are known
+    // (so "Juan Pérez" may be "Juan Pérez González", but "Juan González" may not)
+    fn iberian_surnames_consistent(&self, other: &Name, min_char_match: usize) -> bool {
+        if !surname_words_consistent(
+            self.paternal_surname_iter(),
+            other.paternal_surname_iter(),
+            min_char_match,
+        ) {
+            return false;
+        }
 
-        // Require either an exact match (ignoring case etc), or a partial match
-        // of len >= MIN_SURNAME_CHAR_MATCH and breaking on a word boundary
-        loop {
-            // No words remaining for some surname - that's ok if it's true of
-            // both, or if the components that match are long enough
-            if my_word.is_none() && their_word.is_none() {
-                return true;
-            } else if my_word.is_none() || their_word.is_none() {
-                return matching_chars >= MIN_SURNAME_CHAR_MATCH;
-            }
+        match (self.maternal_surname_iter(), other.maternal_surname_iter()) {
+            (Some(mine), Some(theirs)) => surname_words_consistent(mine, theirs, min_char_match),
+            _ => true,
+        }
+    }
 
-            macro_rules! reverse_lowercase_alpha_chars {
-                ($word:expr) => {
-                    $word
-                        .unwrap()
-                        .chars()
-                        .flat_map(transliterate)
-                        .rev()
-                        .filter_map(lowercase_if_alpha)
-                };
-            }
+    // Only when the given names are identical, to limit false positives
+    fn surname_typo_consistent(&self, other: &Name, options: &MatchOptions) -> bool {
+        if !options.allow_surname_typos {
+            return false;
+        }
 
-            let mut my_chars = reverse_lowercase_alpha_chars!(my_word);
-            let mut their_chars = reverse_lowercase_alpha_chars!(their_word);
+        match (self.given_name(), other.given_name()) {
+            (Some(mine), Some(theirs))
+                if transliterated_lowercase(mine) == transliterated_lowercase(theirs) => {}
+            _ => return false,
+        }
 
-            let mut my_char = my_chars.next();
-            let mut their_char = their_chars.next();
+        if !within_one_typo(
+            &self.paternal_surname_letters(),
+            &other.paternal_surname_letters(),
+        ) {
+            return false;
+        }
 
-            loop {
-                if my_char.is_none() && their_char.is_none() {
-                    // The words matched exactly, try the next word
-                    my_word = my_words.next();
-                    their_word = their_words.next();
-                    break;
-                } else if my_char.is_none() {
-                    // My word is a suffix of their word, check my next word
-                    // against the rest of their word
-                    my_word = my_words.next();
-                    if my_word.is_none() {
-                        // There is no next word, so this is a suffix-only match,
-                        // and we don't allow those
-                        return false;
-                    } else {
-                        // Continue the inner loop but incrementing through my
-                        // next word
-                        my_chars = reverse_lowercase_alpha_chars!(my_word);
-                        my_char = my_chars.next();
-                    }
-                } else if their_char.is_none() {
-                    // Their word is a suffix of my word, check their next word
-                    // against the rest of my_words
-                    their_word = their_words.next();
-                    if their_word.is_none() {
-                        // There is no next word, so this is a suffix-only match,
-                        // and we don't allow those
-                        return false;
-                    } else {
-                        // Continue the inner loop but incrementing through their
-                        // next word
-                        their_chars = reverse_lowercase_alpha_chars!(their_word);
-                        their_char = their_chars.next();
-                    }
-                } else if my_char != their_char {
-                    // We found a conflict and can short-circuit
-                    return false;
-                } else {
-                    // Characters matched, continue the inner loop
-                    matching_chars += 1;
-                    my_char = my_chars.next();
-                    their_char = their_chars.next();
-                }
+        match (self.maternal_surname_iter(), other.maternal_surname_iter()) {
+            (Some(mine), Some(theirs)) => {
+                surname_words_consistent(mine, theirs, options.min_surname_char_match())
             }
+            _ => true,
         }
     }
 
@@ -380,13 +932,198 @@ impl Name {
         self.surname_words() == 1 && self.surname().chars().all(is_ascii_alphabetic)
     }
 
-    fn suffix_consistent(&self, other: &Name) -> bool {
+    fn patronymic_consistent(&self, other: &Name) -> bool {
+        match (self.patronymic(), other.patronymic()) {
+            (Some(mine), Some(theirs)) => mine
+                .chars()
+                .flat_map(transliterate)
+                .filter_map(lowercase_if_alpha)
+                .eq(theirs
+                    .chars()
+                    .flat_map(transliterate)
+                    .filter_map(lowercase_if_alpha)),
+            (Some(_), None) => self.patronymic_consistent_with_middle_names(other),
+            (None, Some(_)) => other.patronymic_consistent_with_middle_names(self),
+            (None, None) => true,
+        }
+    }
+
+    // A patronymic missing from the other name must still agree with any
+    // middle name or initial the other has in its place (so "Ivan Ivanovich
+    // Petrov" is consistent with "I. I. Petrov", but not "I. S. Petrov")
+    fn patronymic_consistent_with_middle_names(&self, other: &Name) -> bool {
+        let end = self.given_names_end();
+        if end == self.surname_index {
+            // Icelandic patronymics stand in for the surname
+            return true;
+        }
+
+        let their_parts = other.given_parts();
+        let theirs = match their_parts.get(self.given_parts().len()) {
+            Some(part) => part,
+            None => return true,
+        };
+
+        let mine = match self
+            .word_iter(end..self.surname_index)
+            .find(|w| !is_patronymic_particle(w))
+        {
+            Some(word) => transliterated_lowercase(word),
+            None => return true,
+        };
+
+        match theirs.word {
+            Some(word) => {
+                let word = transliterated_lowercase(word);
+                mine.starts_with(&*word) || word.starts_with(&*mine)
+            }
+            None => theirs
+                .initials
+                .chars()
+                .flat_map(transliterate)
+                .filter_map(lowercase_if_alpha)
+                .next()
+                .map_or(true, |c| mine.starts_with(c)),
+        }
+    }
+
+    fn suffix_consistent(&self, other: &Name, options: &MatchOptions) -> bool {
+        if options.require_equal_suffixes {
+            return self.generation_from_suffix == other.generation_from_suffix;
+        }
+
         self.generation_from_suffix.is_none()
             || other.generation_from_suffix.is_none()
             || self.generation_from_suffix == other.generation_from_suffix
     }
 }
 
+fn transliterated_lowercase(s: &str) -> String {
+    s.chars()
+        .flat_map(transliterate)
+        .filter_map(lowercase_if_alpha)
+        .collect()
+}
+
+// Whether one insertion, deletion or substitution of a letter, or one
+// transposition of adjacent letters, turns one word into the other (but not
+// for very short words, where that's too likely by chance)
+fn within_one_typo(a: &[char], b: &[char]) -> bool {
+    let (shorter, longer) = if a.len() <= b.len() { (a, b) } else { (b, a) };
+
+    if shorter.len() < MIN_SURNAME_CHAR_MATCH || longer.len() - shorter.len() > 1 {
+        return false;
+    }
+
+    let i = shorter
+        .iter()
+        .zip(longer)
+        .take_while(|&(x, y)| x == y)
+        .count();
+
+    if i == shorter.len() {
+        // Identical, or one letter added at the end
+        true
+    } else if shorter.len() < longer.len() {
+        shorter[i..] == longer[i + 1..]
+    } else if shorter[i + 1..] == longer[i + 1..] {
+        true
+    } else {
+        i + 1 < shorter.len()
+            && shorter[i] == longer[i + 1]
+            && shorter[i + 1] == longer[i]
+            && shorter[i + 2..] == longer[i + 2..]
+    }
+}
+
+fn surname_words_consistent(
+    my_surname: Words,
+    their_surname: Words,
+    min_char_match: usize,
+) -> bool {
+    let mut my_words = my_surname.flat_map(|w| w.unicode_words()).rev();
+
+    let mut their_words = their_surname.flat_map(|w| w.unicode_words()).rev();
+
+    let mut my_word = my_words.next();
+    let mut their_word = their_words.next();
+    let mut matching_chars = 0;
+
+    // Require either an exact match (ignoring case etc), or a partial match
+    // of len >= `min_char_match` and breaking on a word boundary
+    loop {
+        // No words remaining for some surname - that's ok if it's true of
+        // both, or if the components that match are long enough
+        if my_word.is_none() && their_word.is_none() {
+            return true;
+        } else if my_word.is_none() || their_word.is_none() {
+            return matching_chars >= min_char_match;
+        }
+
+        macro_rules! reverse_lowercase_alpha_chars {
+            ($word:expr) => {
+                $word
+                    .unwrap()
+                    .chars()
+                    .flat_map(transliterate)
+                    .rev()
+                    .filter_map(lowercase_if_alpha)
+            };
+        }
+
+        let mut my_chars = reverse_lowercase_alpha_chars!(my_word);
+        let mut their_chars = reverse_lowercase_alpha_chars!(their_word);
+
+        let mut my_char = my_chars.next();
+        let mut their_char = their_chars.next();
+
+        loop {
+            if my_char.is_none() && their_char.is_none() {
+                // The words matched exactly, try the next word
+                my_word = my_words.next();
+                their_word = their_words.next();
+                break;
+            } else if my_char.is_none() {
+                // My word is a suffix of their word, check my next word
+                // against the rest of their word
+                my_word = my_words.next();
+                if my_word.is_none() {
+                    // There is no next word, so this is a suffix-only match,
+                    // and we don't allow those
+                    return false;
+                } else {
+                    // Continue the inner loop but incrementing through my
+                    // next word
+                    my_chars = reverse_lowercase_alpha_chars!(my_word);
+                    my_char = my_chars.next();
+                }
+            } else if their_char.is_none() {
+                // Their word is a suffix of my word, check their next word
+                // against the rest of my_words
+                their_word = their_words.next();
+                if their_word.is_none() {
+                    // There is no next word, so this is a suffix-only match,
+                    // and we don't allow those
+                    return false;
+                } else {
+                    // Continue the inner loop but incrementing through their
+                    // next word
+                    their_chars = reverse_lowercase_alpha_chars!(their_word);
+                    their_char = their_chars.next();
+                }
+            } else if my_char != their_char {
+                // We found a conflict and can short-circuit
+                return false;
+            } else {
+                // Characters matched, continue the inner loop
+                matching_chars += 1;
+                my_char = my_chars.next();
+                their_char = their_chars.next();
+            }
+        }
+    }
+}
+
 #[derive(Eq, PartialEq, Debug)]
 enum ComparisonResult {
     Inconsistent,
@@ -395,7 +1132,23 @@ enum ComparisonResult {
     ExactMatch,
     PrefixOfOther(String),
     PrefixOfSelf(String),
-    NicknameMatch,
+    NicknameMatch(VariantKind),
+}
+
+impl ComparisonResult {
+    // None for words which weren't aligned with each other
+    fn outcome(&self) -> Option<Outcome> {
+        match *self {
+            ComparisonResult::Inconsistent => Some(Outcome::Conflict),
+            ComparisonResult::DifferentInitials => None,
+            ComparisonResult::InitialsOnlyMatch => Some(Outcome::InitialsOnly),
+            ComparisonResult::ExactMatch => Some(Outcome::Exact),
+            ComparisonResult::PrefixOfOther(_) | ComparisonResult::PrefixOfSelf(_) => {
+                Some(Outcome::Prefix)
+            }
+            ComparisonResult::NicknameMatch(kind) => Some(kind.into()),
+        }
+    }
 }
 
 impl<'a> NameWordOrInitial<'a> {
@@ -409,6 +1162,7 @@ impl<'a> NameWordOrInitial<'a> {
     fn check_consistency(
         &self,
         other: &NameWordOrInitial,
+        options: &MatchOptions,
         allow_nicknames: bool,
     ) -> ComparisonResult {
         if self.initial().is_none() || self.initial() != other.initial() {
@@ -439,6 +1193,7 @@ impl<'a> NameWordOrInitial<'a> {
                 return ComparisonResult::ExactMatch;
             } else if (my_char.is_none() || their_char.is_none())
                 && matched >= MIN_GIVEN_NAME_CHAR_MATCH
+                && options.allow_given_name_prefixes
             {
                 if their_char.is_some() {
                     return ComparisonResult::PrefixOfOther(format!(
@@ -455,17 +1210,23 @@ impl<'a> NameWordOrInitial<'a> {
                 }
             } else if my_char != their_char {
                 // Failed match; abort, but first, maybe try nickname db
-                if allow_nicknames && have_matching_variants(self.word(), other.word()) {
-                    return ComparisonResult::NicknameMatch;
-                } else {
-                    return ComparisonResult::Inconsistent;
-                }
+                return match matching_variant_kind(self.word(), other.word(), options) {
+                    Some(kind) if allow_nicknames => ComparisonResult::NicknameMatch(kind),
+                    _ => ComparisonResult::Inconsistent,
+                };
             } else {
                 matched += 1;
             }
         }
     }
 
+    fn text(&self) -> String {
+        match *self {
+            NameWordOrInitial::Word(word, _) => word.to_string(),
+            NameWordOrInitial::Initial(initial) => initial.to_string(),
+        }
+    }
+
     fn word(&self) -> &str {
         match *self {
             NameWordOrInitial::Word(word, _) => word,
@@ -488,6 +1249,115 @@ impl<'a> NameWordOrInitial<'a> {
     }
 }
 
+// Compares given and middle names or initials in order, where `my_parts` are
+// those of the more complete name; when `aligned` is given, records the words
+// or initials compared
+fn parts_consistent<'a, 'b, I, J>(
+    my_parts: I,
+    mut their_parts: J,
+    options: &MatchOptions,
+    missing_any_names: bool,
+    mut aligned: Option<&mut Vec<AlignedPair>>,
+) -> bool
+where
+    I: Iterator<Item = NameWordOrInitial<'a>>,
+    J: Iterator<Item = NameWordOrInitial<'b>>,
+{
+    let mut suffix_for_prior_prefix_match: Option<String> = None;
+    let mut looked_up_nicknames = false;
+
+    let mut their_part_if_any = their_parts.next();
+
+    for my_part in my_parts {
+        if let Some(ref their_part) = their_part_if_any {
+            let result = my_part.check_consistency(their_part, options, !looked_up_nicknames);
+
+            if let Some(ref mut aligned) = aligned {
+                if let Some(outcome) = result.outcome() {
+                    aligned.push(AlignedPair {
+                        mine: my_part.text(),
+                        theirs: their_part.text(),
+                        outcome,
+                    });
+                }
+            }
+
+            match result {
+                ComparisonResult::Inconsistent => {
+                    // The names are inconsistent
+                    return false;
+                }
+                ComparisonResult::DifferentInitials => {
+                    // They don't have a word for this initial, so we don't
+                    // advance the iterator for their words/initials
+                    continue;
+                }
+                ComparisonResult::NicknameMatch(_) => {
+                    looked_up_nicknames = true;
+                }
+                ComparisonResult::PrefixOfOther(remaining_chars) => {
+                    suffix_for_prior_prefix_match = Some(remaining_chars);
+                }
+                _ => {
+                    // Any other kind of match; no-op, just continue
+                }
+            }
+        } else if missing_any_names {
+            // We've matched everything available, and will skip the check
+            // in the next block
+            return true;
+        } else if let Some(suffix) = suffix_for_prior_prefix_match {
+            // We've matched everything available, but we're not quite done.
+            //
+            // It's not uncommon for representations of a name to be inconsistent
+            // in whether two parts of a given name are separated by a space,
+            // a hyphen, or nothing (especially with transliterated names).
+            //
+            // This is one of the reasons we accept prefix-only matches for
+            // given & middle names. However, doing so potentially opens us up
+            // to more false positives, and we want to mitigate that.
+            //
+            // When it looks like we have a name part following a space or hyphen
+            // which might reasonably be treated as part of the same actual name
+            // as the preceding part, we don't want to just accept a prefix-only
+            // match on the preceding part as sufficient for a full match.
+            // Instead we'll continue after the prefix match by comparing the
+            // following part to the suffix, just as if the two parts hadn't
+            // been separated by a space or hyphen.
+            //
+            // This separates, e.g., "Jinli" from "Jin Yi", or "Xiaofeng" from
+            // "Xiao Peng".
+            //
+            // We don't try to do this in the presence of middle initials
+            // without corresponding names, because the logic would have to
+            // be even more complicated.
+            if let NameWordOrInitial::Word(word, _) = my_part {
+                return eq_or_starts_with!(suffix, word);
+            } else {
+                return true;
+            }
+        } else {
+            // We've matched everything available
+            return true;
+        }
+
+        // Skip past as many of their initials as we just compared, which
+        // may span several of their parts, or only part of one
+        let mut advance_by = my_part.initials_count();
+        while advance_by > 0 {
+            match their_part_if_any {
+                Some(ref their_part) => {
+                    advance_by = advance_by.saturating_sub(their_part.initials_count());
+                }
+                None => break,
+            }
+            their_part_if_any = their_parts.next();
+        }
+    }
+
+    their_part_if_any.is_none()
+}
+
 struct GivenNamesOrInitials<'a> {
     initials: Enumerate<Chars<'a>>,
     known_names: Words<'a>,
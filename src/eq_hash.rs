@@ -9,7 +9,8 @@ use std::hash::{Hash, Hasher};
 /// not transitive - "J. Doe" == "Jane Doe", and "J. Doe" == "John Doe", but
 /// "Jane Doe" != "John Doe". (It is, however, symmetric and reflexive.)
 ///
-/// Use with caution! See `consistent_with` docs for details.
+/// Use with caution! See `consistent_with` docs for details, and `cluster`
+/// for grouping names without depending on the order they're seen in.
 impl Eq for Name {}
 impl PartialEq for Name {
     fn eq(&self, other: &Name) -> bool {
@@ -3,6 +3,7 @@
 extern crate libc;
 
 use self::libc::c_char;
+use super::utils::join;
 use super::Name;
 use std::ffi::{CStr, CString};
 use std::mem;
@@ -102,6 +103,28 @@ pub unsafe extern "C" fn human_name_suffix(name: &Name) -> *const c_char {
     option_str_to_char_star!(name.suffix())
 }
 
+#[no_mangle]
+pub unsafe extern "C" fn human_name_nickname(name: &Name) -> *const c_char {
+    option_str_to_char_star!(name.nickname())
+}
+
+#[no_mangle]
+pub unsafe extern "C" fn human_name_patronymic(name: &Name) -> *const c_char {
+    option_str_to_char_star!(name.patronymic().map(|p| p.into_owned()))
+}
+
+#[no_mangle]
+pub unsafe extern "C" fn human_name_titles(name: &Name) -> *const c_char {
+    option_str_to_char_star!(name.titles().map(|t| join(t.into_iter()).into_owned()))
+}
+
+#[no_mangle]
+pub unsafe extern "C" fn human_name_postfix_titles(name: &Name) -> *const c_char {
+    option_str_to_char_star!(name
+        .postfix_titles()
+        .map(|t| join(t.into_iter()).into_owned()))
+}
+
 #[no_mangle]
 pub unsafe extern "C" fn human_name_display_first_last(name: &Name) -> *const c_char {
     str_to_char_star!(name.display_first_last().into_owned())
@@ -0,0 +1,390 @@
+use super::comparison::MatchScore;
+use super::nickname::variants;
+use super::utils::{lowercase_if_alpha, to_ascii_letter};
+use super::{GivenPart, Name};
+use std::collections::HashMap;
+use std::hash::Hash;
+use std::iter::FromIterator;
+
+/// Many names, indexed to quickly find those which may represent the same
+/// person as another.
+///
+/// Bucketing names by `hash` alone isn't enough to look them up at scale,
+/// since names with common surnames share a bucket (see `surname_hash`).
+/// Within each bucket, names are also keyed by their first initial, by their
+/// middle initials, and by the first and last letters of their given name and
+/// of its variants in our nickname tables (and likewise of any nickname
+/// captured from the name, which may stand in for its given name), so each
+/// lookup only compares the query with names which share a key with it, but
+/// still finds every name `consistent_with` it.
+///
+/// ```
+/// use human_name::{Name, NameIndex};
+///
+/// let index: NameIndex = ["Jane Doe", "John Doe", "J. Doe", "William Gates"]
+///     .iter()
+///     .map(|name| Name::parse(name).unwrap())
+///     .collect();
+///
+/// let query = Name::parse("Jane A. Doe").unwrap();
+/// let found: Vec<&str> = index
+///     .find_consistent(&query)
+///     .map(|name| name.display_full())
+///     .collect();
+/// assert_eq!(vec!["Jane Doe", "J. Doe"], found);
+///
+/// let query = Name::parse("Bill Gates").unwrap();
+/// assert_eq!("William Gates", index.best_match(&query).unwrap().display_full());
+/// ```
+#[derive(Clone, Debug, Default)]
+pub struct NameIndex {
+    names: Vec<Name>,
+    buckets: HashMap<u64, Bucket>,
+}
+
+// Names sharing a `hash`, by their position in `NameIndex::names`
+#[derive(Clone, Debug, Default)]
+struct Bucket {
+    by_first_initial: HashMap<Option<char>, Vec<usize>>,
+    // Only names whose first given name we know only by its initial
+    by_bare_first_initial: HashMap<Option<char>, Vec<usize>>,
+    by_middle_initial: HashMap<Option<char>, Vec<usize>>,
+    // Only names whose first given name we know, by the first two letters of
+    // the name and of each of its variants
+    by_given_name_prefix: HashMap<String, Vec<usize>>,
+    // Only names with a given name and no middle names or initials, by the
+    // last two letters of the name and of each of its variants
+    by_given_name_suffix: HashMap<String, Vec<usize>>,
+}
+
+impl NameIndex {
+    pub fn new() -> NameIndex {
+        Default::default()
+    }
+
+    /// Adds a name to the index, returning its position in `names`.
+    pub fn insert(&mut self, name: Name) -> usize {
+        let i = self.names.len();
+
+        {
+            let bucket = self
+                .buckets
+                .entry(name.hash)
+                .or_insert_with(Default::default);
+            let parts = name.given_parts();
+            let first_initial = initial_key(&parts[0]);
+
+            add(&mut bucket.by_first_initial, first_initial, i);
+            match parts[0].word {
+                Some(word) => {
+                    for prefix in variant_prefixes(word) {
+                        add(&mut bucket.by_given_name_prefix, prefix, i);
+                    }
+                }
+                None => add(&mut bucket.by_bare_first_initial, first_initial, i),
+            }
+
+            if let (Some(word), None) = (name.given_name(), name.middle_initials()) {
+                for suffix in variant_suffixes(word) {
+                    add(&mut bucket.by_given_name_suffix, suffix, i);
+                }
+            }
+
+            // The nickname may stand in for the first given name
+            if let Some(part) = nickname_part(&name) {
+                add(&mut bucket.by_first_initial, initial_key(&part), i);
+                if let Some(word) = part.word {
+                    for prefix in variant_prefixes(word) {
+                        add(&mut bucket.by_given_name_prefix, prefix, i);
+                    }
+                    if name.middle_initials().is_none() {
+                        for suffix in variant_suffixes(word) {
+                            add(&mut bucket.by_given_name_suffix, suffix, i);
+                        }
+                    }
+                }
+            }
+
+            let mut middle_initials: Vec<Option<char>> =
+                name.initials.chars().skip(1).map(to_ascii_letter).collect();
+            middle_initials.sort();
+            middle_initials.dedup();
+            for initial in middle_initials {
+                add(&mut bucket.by_middle_initial, initial, i);
+            }
+        }
+
+        self.names.push(name);
+        i
+    }
+
+    /// Every name in the index, in the order they were inserted.
+    pub fn names(&self) -> &[Name] {
+        &self.names
+    }
+
+    pub fn len(&self) -> usize {
+        self.names.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.names.is_empty()
+    }
+
+    /// Names in the index consistent with the given name (see
+    /// `consistent_with`), in the order they were inserted.
+    pub fn find_consistent<'a>(&'a self, name: &'a Name) -> impl Iterator<Item = &'a Name> + 'a {
+        let mut candidates = Vec::new();
+
+        if let Some(bucket) = self.buckets.get(&name.hash) {
+            let parts = name.given_parts();
+
+            // Another name may skip any of our given or middle names, so
+            // its first may be any of ours, or we may skip any of its
+            for part in &parts {
+                bucket.add_candidates_with_first(part, &mut candidates);
+            }
+            extend(
+                &bucket.by_middle_initial,
+                &initial_key(&parts[0]),
+                &mut candidates,
+            );
+
+            // Without middle names, given names may also match by their final
+            // syllables, as in "Beth" and "Elizabeth"
+            if let (Some(word), None) = (name.given_name(), name.middle_initials()) {
+                for suffix in variant_suffixes(word) {
+                    extend(&bucket.by_given_name_suffix, &suffix, &mut candidates);
+                }
+            }
+
+            // Our nickname may likewise stand in for our first given name
+            if let Some(part) = nickname_part(name) {
+                bucket.add_candidates_with_first(&part, &mut candidates);
+                extend(
+                    &bucket.by_middle_initial,
+                    &initial_key(&part),
+                    &mut candidates,
+                );
+                if let (Some(word), None) = (part.word, name.middle_initials()) {
+                    for suffix in variant_suffixes(word) {
+                        extend(&bucket.by_given_name_suffix, &suffix, &mut candidates);
+                    }
+                }
+            }
+        }
+
+        candidates.sort();
+        candidates.dedup();
+
+        candidates
+            .into_iter()
+            .map(move |i| &self.names[i])
+            .filter(move |other| name.consistent_with(other))
+    }
+
+    /// The name in the index most similar to the given name (see
+    /// `similarity`), if any is consistent with it. Ties go to the name
+    /// inserted first.
+    pub fn best_match<'a>(&'a self, name: &'a Name) -> Option<&'a Name> {
+        let mut best: Option<(&Name, MatchScore)> = None;
+
+        for other in self.find_consistent(name) {
+            let score = name.similarity(other);
+            if best.map_or(true, |(_, best_score)| score > best_score) {
+                best = Some((other, score));
+            }
+        }
+
+        best.map(|(other, _)| other)
+    }
+}
+
+impl Bucket {
+    // Names which may have the given part as their first given name
+    fn add_candidates_with_first(&self, part: &GivenPart, candidates: &mut Vec<usize>) {
+        let initial = initial_key(part);
+
+        match part.word {
+            Some(word) => {
+                extend(&self.by_bare_first_initial, &initial, candidates);
+                for prefix in variant_prefixes(word) {
+                    extend(&self.by_given_name_prefix, &prefix, candidates);
+                }
+            }
+            None => extend(&self.by_first_initial, &initial, candidates),
+        }
+    }
+}
+
+impl FromIterator<Name> for NameIndex {
+    fn from_iter<I: IntoIterator<Item = Name>>(names: I) -> NameIndex {
+        let mut index = NameIndex::new();
+        index.extend(names);
+        index
+    }
+}
+
+impl Extend<Name> for NameIndex {
+    fn extend<I: IntoIterator<Item = Name>>(&mut self, names: I) {
+        for name in names {
+            self.insert(name);
+        }
+    }
+}
+
+// A captured nickname, as a given name in its own right
+fn nickname_part<'a>(name: &'a Name) -> Option<GivenPart<'a>> {
+    name.nickname().and_then(|nickname| {
+        nickname.chars().next().map(|c| GivenPart {
+            initials: &nickname[..c.len_utf8()],
+            word: Some(nickname),
+        })
+    })
+}
+
+// Transliterated, as when comparing initials
+fn initial_key(part: &GivenPart) -> Option<char> {
+    part.initials.chars().next().and_then(to_ascii_letter)
+}
+
+// Two given names can only be consistent if they, or some variants of
+// them, share a first initial and then their first two letters, or else end
+// with the same two letters (since we don't treat a single letter as a name,
+// only as an initial)
+fn variant_prefixes(word: &str) -> Vec<String> {
+    variant_keys(word, |letters| letters.iter().take(2).collect())
+}
+
+fn variant_suffixes(word: &str) -> Vec<String> {
+    variant_keys(word, |letters| {
+        letters[letters.len().saturating_sub(2)..].iter().collect()
+    })
+}
+
+fn variant_keys<F: Fn(&[char]) -> String>(word: &str, key: F) -> Vec<String> {
+    let mut keys: Vec<String> = variants(word)
+        .iter()
+        .map(|variant| {
+            let letters: Vec<char> = variant.chars().filter_map(lowercase_if_alpha).collect();
+            key(&letters)
+        })
+        .collect();
+    keys.sort();
+    keys.dedup();
+    keys
+}
+
+fn add<K: Eq + Hash>(map: &mut HashMap<K, Vec<usize>>, key: K, i: usize) {
+    map.entry(key).or_insert_with(Vec::new).push(i);
+}
+
+fn extend<K: Eq + Hash>(map: &HashMap<K, Vec<usize>>, key: &K, candidates: &mut Vec<usize>) {
+    if let Some(indices) = map.get(key) {
+        candidates.extend_from_slice(indices);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn index(names: &[&str]) -> NameIndex {
+        names
+            .iter()
+            .map(|name| Name::parse(name).unwrap())
+            .collect()
+    }
+
+    fn find(index: &NameIndex, query: &str) -> Vec<String> {
+        let query = Name::parse(query).unwrap();
+        index
+            .find_consistent(&query)
+            .map(|name| name.display_full().to_string())
+            .collect()
+    }
+
+    #[test]
+    fn same_as_consistent_with() {
+        let names = [
+            "Jane Doe",
+            "J. Doe",
+            "Jane M. Doe",
+            "J. Mary Doe",
+            "Mary Doe",
+            "M. Doe",
+            "Janie Doe",
+            "John Doe",
+            "Jon Doe",
+            "T Boone Doe",
+            "Thomas Doe",
+            "Boone Doe",
+            "Thomas Boone Doe",
+            "Bill Doe",
+            "William Doe",
+            "William J. Doe",
+            "Đorđe Doe",
+            "Djordje Doe",
+            "Jean-Paul Doe",
+            "Paul Doe",
+            "J. P. Doe",
+            "Hua Doe",
+            "Zhenhua Doe",
+            "Jane Smith",
+            "Robert 'Skip' Doe",
+            "Skip Doe",
+            "J. Skip Doe",
+            "Skip A. Doe",
+            "Robert (Skip) A. Doe",
+        ];
+        let index = index(&names);
+
+        for query in &names {
+            let query = Name::parse(query).unwrap();
+            let expected: Vec<&str> = index
+                .names()
+                .iter()
+                .filter(|name| query.consistent_with(name))
+                .map(|name| name.display_full())
+                .collect();
+            let found: Vec<&str> = index
+                .find_consistent(&query)
+                .map(|name| name.display_full())
+                .collect();
+            assert_eq!(expected, found, "{}", query.display_full());
+        }
+    }
+
+    #[test]
+    fn goes_by_middle_name() {
+        let index = index(&["T Boone Pickens", "Thomas Pickens", "John Pickens"]);
+        assert_eq!(vec!["T. Boone Pickens"], find(&index, "Boone Pickens"));
+        assert_eq!(
+            vec!["T. Boone Pickens", "Thomas Pickens"],
+            find(&index, "Thomas Boone Pickens")
+        );
+    }
+
+    #[test]
+    fn best_match() {
+        let index = index(&["J. Doe", "Jane Doe", "Jane M. Doe", "John Doe"]);
+        let query = Name::parse("Jane Mary Doe").unwrap();
+        assert_eq!(
+            "Jane M. Doe",
+            index.best_match(&query).unwrap().display_full()
+        );
+
+        let query = Name::parse("Jane Smith").unwrap();
+        assert!(index.best_match(&query).is_none());
+    }
+
+    #[test]
+    fn positions() {
+        let mut index = NameIndex::new();
+        assert!(index.is_empty());
+        assert_eq!(0, index.insert(Name::parse("Jane Doe").unwrap()));
+        assert_eq!(1, index.insert(Name::parse("John Doe").unwrap()));
+        assert_eq!(2, index.len());
+        assert_eq!("John Doe", index.names()[1].display_full());
+    }
+}
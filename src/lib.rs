@@ -9,7 +9,12 @@
 #![plugin(phf_macros)]
 
 extern crate phf;
+#[cfg(feature = "rustc-serialize")]
 extern crate rustc_serialize;
+#[cfg(feature = "serde")]
+extern crate serde;
+#[cfg(all(test, feature = "serde"))]
+extern crate serde_json;
 extern crate smallstr;
 extern crate smallvec;
 extern crate test;
@@ -19,13 +24,21 @@ extern crate unidecode;
 
 #[macro_use]
 mod utils;
+mod cluster;
+mod cognate;
 mod comparison;
+mod index;
+mod list;
+mod merge;
 mod namecase;
 mod namepart;
 mod nickname;
 mod parse;
+mod patronymic;
+mod phonetic;
 mod segment;
-mod serialization;
+mod slug;
+mod span;
 mod suffix;
 mod surname;
 mod title;
@@ -33,15 +46,36 @@ mod web_match;
 
 pub mod external;
 
+pub use cluster::cluster;
+pub use comparison::{
+    AlignedPair, ComparisonReport, ComponentReport, MatchOptions, MatchScore, Outcome,
+};
+pub use index::NameIndex;
+pub use list::NameList;
+pub use nickname::{variants_of, NicknameDb, NicknameDbError};
+pub use parse::{NameOrder, ParseError, ParseOptions};
+pub use phonetic::{PhoneticKey, PhoneticKeys};
+pub use slug::{infer_slug_convention, SlugConvention, SlugPattern};
+pub use span::NameSpans;
+pub use web_match::SlugMatch;
+
 #[cfg(feature = "name_eq_hash")]
 mod eq_hash;
 
-use namepart::NamePart;
+#[cfg(feature = "rustc-serialize")]
+mod serialization;
+
+#[cfg(feature = "serde")]
+mod serde_support;
+
+use namepart::{Location, NamePart};
+use parse::Titles;
 use smallstr::SmallString;
 use smallvec::SmallVec;
 use std::borrow::Cow;
 use std::collections::hash_map::DefaultHasher;
 use std::hash::{Hash, Hasher};
+use std::iter;
 use std::ops::Range;
 use std::slice::Iter;
 use utils::{lowercase_if_alpha, normalize_nfkd_hyphens_spaces, transliterate, join};
@@ -53,8 +87,9 @@ pub const MAX_SEGMENTS: usize = parse::MAX_WORDS;
 /// Represents a parsed human name.
 ///
 /// Guaranteed to contain (what we think is) a surname, a first initial, and
-/// nothing more. May also contain given & middle names, middle initials, and/or
-/// a generational suffix.
+/// nothing more. May also contain given & middle names, middle initials,
+/// a patronymic, a generational suffix, a nickname, and/or prefix and
+/// postfix titles.
 ///
 /// Construct a Name using `parse`:
 ///
@@ -75,6 +110,11 @@ pub struct Name {
     generation_from_suffix: Option<u8>,
     initials: SmallString<[u8; 8]>,
     word_indices_in_initials: SmallVec<[Range<usize>; 3]>,
+    prefix_titles: Vec<Box<str>>,
+    postfix_titles: Vec<Box<str>>,
+    nickname: Option<Box<str>>,
+    patronymic: Option<Range<usize>>,
+    iberian_surnames: bool,
     pub hash: u64,
 }
 
@@ -121,14 +161,16 @@ impl Name {
     /// Single-word names cannot be parsed (you may or may not wish to assume
     /// they are given names).
     ///
-    /// Does not preserve titles (other than generational suffixes such as "III")
-    /// or nicknames. Does not handle plural forms specially: "Mr. & Mrs. John
-    /// Doe" will be parsed as "John Doe", and "Jane Doe, et al" will be parsed
-    /// as "Jane Doe".
+    /// Keeps titles and nicknames separate from the name itself (see `titles`,
+    /// `postfix_titles` and `nickname`). Does not handle plural forms
+    /// specially: "Mr. & Mrs. John Doe" will be parsed as "John Doe", and
+    /// "Jane Doe, et al" will be parsed as "Jane Doe" (see `parse_many` to
+    /// parse strings naming more than one person).
     ///
     /// Works best on Latin names - i.e., data from North or South America or
     /// Europe. Does not understand surname-first formats without commas: "Kim
-    /// Il-sung" will be parsed as having the first name "Kim".
+    /// Il-sung" will be parsed as having the first name "Kim" (but see
+    /// `parse_with_options`).
     ///
     /// Handles non-Latin unicode strings, but without any particular intelligence.
     /// Attempts at least to fail nicely, such that either `parse` returns `None`,
@@ -139,29 +181,243 @@ impl Name {
     /// for canonicalizing names. The goal here is to do the best we can without
     /// large statistical models.
     pub fn parse(name: &str) -> Option<Name> {
-        if name.len() >= MAX_NAME_LEN {
-            return None;
+        Name::try_parse(name).ok()
+    }
+
+    /// Like `parse`, but on failure, reports why the input was rejected.
+    ///
+    /// ```
+    /// use human_name::{Name, ParseError};
+    ///
+    /// let name = Name::try_parse("Jane Doe").unwrap();
+    /// assert_eq!("Doe", name.surname());
+    ///
+    /// assert_eq!(Err(ParseError::TooFewWords), Name::try_parse("Dr. Smith").map(|_| ()));
+    /// assert_eq!(Err(ParseError::UnexpectedWord), Name::try_parse("Doe, Jo%%%hn").map(|_| ()));
+    /// ```
+    pub fn try_parse(name: &str) -> Result<Name, ParseError> {
+        Name::try_parse_with_options(name, &ParseOptions::default())
+    }
+
+    /// Like `parse`, but allows overriding the default assumptions about the
+    /// input, such as that the given name comes before the surname.
+    ///
+    /// ```
+    /// use human_name::{Name, NameOrder, ParseOptions};
+    ///
+    /// let options = ParseOptions {
+    ///     name_order: NameOrder::SurnameFirst,
+    ///     ..Default::default()
+    /// };
+    /// let name = Name::parse_with_options("Kim Il-sung", &options).unwrap();
+    /// assert_eq!("Kim", name.surname());
+    /// assert_eq!(Some("Il-sung"), name.given_name());
+    ///
+    /// let options = ParseOptions {
+    ///     name_order: NameOrder::Auto,
+    ///     ..Default::default()
+    /// };
+    /// let name = Name::parse_with_options("鄭和", &options).unwrap();
+    /// assert_eq!("鄭", name.surname());
+    /// assert_eq!(Some("和"), name.given_name());
+    ///
+    /// let name = Name::parse_with_options("Jane Doe", &options).unwrap();
+    /// assert_eq!("Doe", name.surname());
+    /// ```
+    ///
+    /// Names parsed surname-first are still displayed given name first, as
+    /// in "Il-sung Kim".
+    pub fn parse_with_options(name: &str, options: &ParseOptions) -> Option<Name> {
+        Name::try_parse_with_options(name, options).ok()
+    }
+
+    /// Like `parse_with_options`, but on failure, reports why the input was
+    /// rejected.
+    pub fn try_parse_with_options(name: &str, options: &ParseOptions) -> Result<Name, ParseError> {
+        Name::parse_and_locate(name, options, false).map(|(name, _)| name)
+    }
+
+    // Shared by `try_parse_with_spans`, which also needs to know where each
+    // part of the name was in the input (otherwise the spans are empty)
+    fn parse_and_locate(
+        input: &str,
+        options: &ParseOptions,
+        locate: bool,
+    ) -> Result<(Name, NameSpans), ParseError> {
+        if input.len() >= MAX_NAME_LEN {
+            return Err(ParseError::TooLong);
+        }
+
+        let normalized = normalize_nfkd_hyphens_spaces(input);
+        let (name, nickname) = nickname::strip_and_capture_nickname(&normalized);
+
+        let (words, surname_index, generation_from_suffix, titles, title_words) =
+            parse::parse_with_options(&*name, options)?;
+
+        let spans = if locate {
+            span::locate(
+                input,
+                &normalized,
+                &name,
+                &words[..],
+                surname_index,
+                &title_words,
+                nickname,
+            )
+        } else {
+            NameSpans::default()
+        };
+
+        let mut name = Name::initialize_struct(
+            &words,
+            surname_index,
+            generation_from_suffix,
+            &titles,
+            name.len(),
+        );
+
+        name.nickname = nickname.map(|n| nickname::namecase_nickname(n).into());
+        name.iberian_surnames = options.iberian_surnames;
+        name.initialize_hash();
+
+        Ok((name, spans))
+    }
+
+    /// Builds a name from its given name, middle names, surname and suffix,
+    /// for sources which already keep them apart, rather than parsing them
+    /// from one string.
+    ///
+    /// Each part is normalized, namecased and reduced to initials as when
+    /// parsing, but we don't guess where one part ends and the next begins:
+    /// a given name of more than one word, like "Mary Ann", is kept whole
+    /// rather than treated as a given and middle name, and so is a surname,
+    /// like "García Márquez". Parts may be initials, except for the surname.
+    ///
+    /// Fails if a part is empty (`EmptyComponent`), or has a word which is
+    /// neither a name nor initials (`UnexpectedWord`), if the surname has no
+    /// name (`NoSurname`), or if the suffix isn't generational, like "Jr."
+    /// (also `UnexpectedWord`).
+    ///
+    /// ```
+    /// use human_name::{Name, ParseError};
+    ///
+    /// let name = Name::from_components("MARY ANN", &["J."], "GARCIA LOPEZ", Some("jr")).unwrap();
+    /// assert_eq!("Mary Ann J. Garcia Lopez, Jr.", name.display_full());
+    /// assert_eq!(Some("Mary Ann"), name.given_name());
+    /// assert_eq!("Garcia Lopez", name.surname());
+    /// assert_eq!("MJ", name.initials());
+    ///
+    /// assert_eq!(
+    ///     Err(ParseError::EmptyComponent),
+    ///     Name::from_components("Jane", &[], " ", None).map(|_| ())
+    /// );
+    /// assert_eq!(
+    ///     Err(ParseError::UnexpectedWord),
+    ///     Name::from_components("Jane", &["Jo%%%hn"], "Doe", None).map(|_| ())
+    /// );
+    /// ```
+    pub fn from_components(
+        given: &str,
+        middles: &[&str],
+        surname: &str,
+        suffix: Option<&str>,
+    ) -> Result<Name, ParseError> {
+        let len = given.len()
+            + middles.iter().map(|middle| middle.len() + 1).sum::<usize>()
+            + surname.len()
+            + suffix.map_or(0, |suffix| suffix.len() + 1);
+        if len >= MAX_NAME_LEN {
+            return Err(ParseError::TooLong);
+        }
+
+        let given = normalize_nfkd_hyphens_spaces(given);
+        let middles: Vec<Cow<str>> = middles
+            .iter()
+            .map(|middle| normalize_nfkd_hyphens_spaces(middle))
+            .collect();
+        let surname = normalize_nfkd_hyphens_spaces(surname);
+
+        // As if parsing the parts joined together
+        let trust_capitalization = utils::is_mixed_case(&join(
+            iter::once(&*given)
+                .chain(middles.iter().map(|middle| &**middle))
+                .chain(iter::once(&*surname)),
+        ));
+
+        let given_words = component_words(&given, trust_capitalization, Location::Start)?;
+        let given_name: Option<String> =
+            if given_words.len() > 1 && given_words.iter().all(NamePart::is_namelike) {
+                let mut given_name = String::with_capacity(given.len());
+                for word in &given_words {
+                    if !given_name.is_empty() {
+                        given_name.push(' ');
+                    }
+                    word.with_namecased(|s| given_name.push_str(s));
+                }
+                Some(given_name)
+            } else {
+                None
+            };
+
+        let mut words: Vec<NamePart> = match given_name {
+            Some(ref given_name) => vec![NamePart::from_name(given_name)],
+            None => given_words,
+        };
+        for middle in &middles {
+            words.extend(component_words(
+                middle,
+                trust_capitalization,
+                Location::Middle,
+            )?);
         }
 
-        let name = normalize_nfkd_hyphens_spaces(&name);
-        let name = nickname::strip_nickname(&name);
+        let surname_index = words.len();
+        let surname_words = component_words(&surname, trust_capitalization, Location::Middle)?;
+        if !surname_words.iter().any(NamePart::is_namelike) {
+            return Err(ParseError::NoSurname);
+        }
+        words.extend(surname_words);
 
-        let (words, surname_index, generation_from_suffix) = parse::parse(&*name)?;
+        if words.len() > parse::MAX_WORDS {
+            return Err(ParseError::TooManyWords);
+        }
+
+        let generation_from_suffix = match suffix {
+            Some(suffix) => {
+                let suffix = normalize_nfkd_hyphens_spaces(suffix);
+                let part = NamePart::from_word(suffix.trim(), trust_capitalization, Location::End);
+                Some(
+                    suffix::generation_from_suffix(&part, false)
+                        .ok_or(ParseError::UnexpectedWord)?,
+                )
+            }
+            None => None,
+        };
+
+        let titles = Titles {
+            prefix: SmallVec::new(),
+            postfix: SmallVec::new(),
+        };
 
         let mut name =
-            Name::initialize_struct(&words, surname_index, generation_from_suffix, name.len());
+            Name::initialize_struct(&words, surname_index, generation_from_suffix, &titles, len);
+        name.initialize_hash();
 
-        let mut s = DefaultHasher::new();
-        name.surname_hash(&mut s);
-        name.hash = s.finish();
+        Ok(name)
+    }
 
-        Some(name)
+    // Must follow any change to the surname or `iberian_surnames`
+    fn initialize_hash(&mut self) {
+        let mut s = DefaultHasher::new();
+        self.surname_hash(&mut s);
+        self.hash = s.finish();
     }
 
     fn initialize_struct(
         words: &[NamePart],
         surname_index: usize,
         generation_from_suffix: Option<u8>,
+        titles: &Titles,
         name_len: usize,
     ) -> Name {
         let last_word = words.len() - 1;
@@ -173,6 +429,9 @@ impl Name {
         let mut word_indices_in_initials = SmallVec::with_capacity(surname_index);
         let mut word_indices_in_text = SmallVec::with_capacity(words.len());
 
+        let patronymic_index = patronymic::find_patronymic_index(words, surname_index);
+        let mut patronymic_index_in_names = patronymic_index;
+
         for (i, word) in words.iter().enumerate() {
             if word.is_initials() && i < surname_index {
                 word.with_initials(|c| {
@@ -183,6 +442,7 @@ impl Name {
                 });
 
                 surname_index_in_names -= 1;
+                patronymic_index_in_names -= 1;
             } else {
                 let prior_len = text.len();
                 word.with_namecased(|s| text.push_str(s));
@@ -191,7 +451,8 @@ impl Name {
                 if i < last_word {
                     text.push(' ');
 
-                    if i < surname_index {
+                    // Patronymics don't count towards initials
+                    if i < patronymic_index {
                         debug_assert!(word.is_namelike());
 
                         let prior_len = initials.len();
@@ -207,6 +468,16 @@ impl Name {
             text.push_str(suffix::display_generational_suffix(suffix));
         }
 
+        let patronymic = if patronymic_index < surname_index {
+            Some(patronymic_index_in_names..surname_index_in_names)
+        } else if surname_index + 1 == words.len()
+            && patronymic::is_icelandic_patronymic(words[surname_index].word)
+        {
+            Some(surname_index_in_names..surname_index_in_names + 1)
+        } else {
+            None
+        };
+
         debug_assert!(!text.is_empty(), "Names are empty!");
         debug_assert!(!initials.is_empty(), "Initials are empty!");
 
@@ -222,6 +493,11 @@ impl Name {
             generation_from_suffix,
             initials,
             word_indices_in_initials,
+            prefix_titles: titles.prefix.iter().map(|t| t.clone().into()).collect(),
+            postfix_titles: titles.postfix.iter().map(|t| t.clone().into()).collect(),
+            nickname: None,
+            patronymic,
+            iberian_surnames: false,
             hash: 0,
         }
     }
@@ -246,6 +522,22 @@ impl Name {
         self.given_iter().nth(0)
     }
 
+    /// Nickname, if one was given in quotes or brackets
+    ///
+    /// ```
+    /// use human_name::Name;
+    ///
+    /// let name = Name::parse("William Gates").unwrap();
+    /// assert_eq!(None, name.nickname());
+    ///
+    /// let name = Name::parse("William (Bill) Gates").unwrap();
+    /// assert_eq!(Some("Bill"), name.nickname());
+    /// assert_eq!(Some("William"), name.given_name());
+    /// ```
+    pub fn nickname(&self) -> Option<&str> {
+        self.nickname.as_ref().map(|n| &**n)
+    }
+
     /// Does this person use a middle name in place of their given name?
     ///
     /// ```
@@ -267,6 +559,46 @@ impl Name {
             .any(|r| r.start > 0)
     }
 
+    /// Prefix titles as an array of words, if present
+    ///
+    /// ```
+    /// use human_name::Name;
+    ///
+    /// let name = Name::parse("Jane Doe").unwrap();
+    /// assert_eq!(None, name.titles());
+    ///
+    /// let name = Name::parse("Right Hon. Jane Doe").unwrap();
+    /// assert_eq!(vec!["Right", "Hon."], name.titles().unwrap().to_vec());
+    /// ```
+    pub fn titles(&self) -> Option<SmallVec<[&str; 3]>> {
+        if self.prefix_titles.is_empty() {
+            None
+        } else {
+            Some(self.prefix_titles.iter().map(|t| &**t).collect())
+        }
+    }
+
+    /// Postfix titles, such as credentials or "Esq.", as an array of words,
+    /// if present. Does not include generational suffixes (see `suffix`).
+    ///
+    /// ```
+    /// use human_name::Name;
+    ///
+    /// let name = Name::parse("Jane Doe").unwrap();
+    /// assert_eq!(None, name.postfix_titles());
+    ///
+    /// let name = Name::parse("James S. Brown Jr., MD, FRCS").unwrap();
+    /// assert_eq!(vec!["MD", "FRCS"], name.postfix_titles().unwrap().to_vec());
+    /// assert_eq!(Some("Jr."), name.suffix());
+    /// ```
+    pub fn postfix_titles(&self) -> Option<SmallVec<[&str; 3]>> {
+        if self.postfix_titles.is_empty() {
+            None
+        } else {
+            Some(self.postfix_titles.iter().map(|t| &**t).collect())
+        }
+    }
+
     /// First and middle initials as a string (always present)
     ///
     /// ```
@@ -353,6 +685,94 @@ impl Name {
         join(self.surname_iter())
     }
 
+    /// Paternal surname, for names parsed with the `iberian_surnames` option
+    /// (otherwise `None`)
+    ///
+    /// ```
+    /// use human_name::{Name, ParseOptions};
+    ///
+    /// let options = ParseOptions {
+    ///     iberian_surnames: true,
+    ///     ..Default::default()
+    /// };
+    ///
+    /// let name = Name::parse_with_options("Gabriel Garcia Marquez", &options).unwrap();
+    /// assert_eq!("Garcia Marquez", name.surname());
+    /// assert_eq!("Garcia", name.paternal_surname().unwrap());
+    /// assert_eq!("Marquez", name.maternal_surname().unwrap());
+    ///
+    /// let name = Name::parse_with_options("Juan Velasquez y Garcia", &options).unwrap();
+    /// assert_eq!("Velasquez", name.paternal_surname().unwrap());
+    /// assert_eq!("Garcia", name.maternal_surname().unwrap());
+    ///
+    /// let name = Name::parse_with_options("Gabriel Garcia", &options).unwrap();
+    /// assert_eq!("Garcia", name.paternal_surname().unwrap());
+    /// assert_eq!(None, name.maternal_surname());
+    ///
+    /// let name = Name::parse("Gabriel Garcia Marquez").unwrap();
+    /// assert_eq!("Marquez", name.surname());
+    /// assert_eq!(None, name.paternal_surname());
+    /// ```
+    pub fn paternal_surname(&self) -> Option<Cow<str>> {
+        if self.iberian_surnames {
+            Some(join(self.paternal_surname_iter()))
+        } else {
+            None
+        }
+    }
+
+    /// Maternal surname, for names parsed with the `iberian_surnames` option,
+    /// if present (see `paternal_surname`)
+    pub fn maternal_surname(&self) -> Option<Cow<str>> {
+        self.maternal_surname_iter().map(|i| join(i))
+    }
+
+    /// Patronymic, if recognized, as a string. Slavic patronymics (e.g.
+    /// "Ivanovich" or "Petrovna") are recognized between the given and
+    /// surname, as are chains of Arabic patronymics (e.g. "bin Abdulaziz")
+    /// with `ParseOptions::arabic_patronymics`, and are not included in
+    /// `middle_names` or `middle_initials`.
+    ///
+    /// Icelandic patronymics (e.g. "Stefánsson" or "Jónsdóttir") are also
+    /// recognized in place of a surname, in which case they are included in
+    /// `surname` as well.
+    ///
+    /// ```
+    /// use human_name::{Name, ParseOptions};
+    ///
+    /// let name = Name::parse("Ivan Ivanovich Petrov").unwrap();
+    /// assert_eq!("Ivanovich", name.patronymic().unwrap());
+    /// assert_eq!(None, name.middle_name());
+    /// assert_eq!("I", name.initials());
+    /// assert_eq!("Petrov", name.surname());
+    ///
+    /// let options = ParseOptions {
+    ///     arabic_patronymics: true,
+    ///     ..Default::default()
+    /// };
+    /// let name = Name::parse_with_options("Mohammed bin Salman Al Saud", &options).unwrap();
+    /// assert_eq!("Bin Salman", name.patronymic().unwrap());
+    /// assert_eq!("Al Saud", name.surname());
+    ///
+    /// let name = Name::parse("Bjork Gudmundsdottir").unwrap();
+    /// assert_eq!("Gudmundsdottir", name.patronymic().unwrap());
+    /// assert_eq!("Gudmundsdottir", name.surname());
+    ///
+    /// let name = Name::parse("John Quincy Adams").unwrap();
+    /// assert_eq!(None, name.patronymic());
+    ///
+    /// let name = Name::parse("Luc Besson").unwrap();
+    /// assert_eq!(None, name.patronymic());
+    ///
+    /// let name = Name::parse("Anna Karlsson").unwrap();
+    /// assert_eq!(None, name.patronymic());
+    /// ```
+    pub fn patronymic(&self) -> Option<Cow<str>> {
+        self.patronymic
+            .as_ref()
+            .map(|range| join(self.word_iter(range.clone())))
+    }
+
     /// Generational suffix, if present
     pub fn suffix(&self) -> Option<&str> {
         self.generation_from_suffix
@@ -460,11 +880,14 @@ impl Name {
     /// when one surname ends with the other and the smaller is at least four
     /// characters, to catch cases like "Iria Gayo" == "Iria del Río Gayo".
     ///
+    /// For names parsed with the `iberian_surnames` option, we use the paternal
+    /// surname instead, since the maternal surname is often omitted.
+    ///
     /// We can't use the first initial because we might ignore it if someone goes
     /// by a middle name or nickname, or due to transliteration.
     pub fn surname_hash<H: Hasher>(&self, state: &mut H) {
         for c in self
-            .surname_iter()
+            .paternal_surname_iter()
             .flat_map(|w| w.chars())
             .flat_map(transliterate)
             .rev()
@@ -475,6 +898,43 @@ impl Name {
         }
     }
 
+    /// Hashes for finding candidate matches among many names, when matching
+    /// with `MatchOptions::allow_surname_typos`.
+    ///
+    /// That option may find names consistent although their surnames are a
+    /// typo apart, as in "James Hanson" and "James Hansen", and so their
+    /// `hash`es differ. Any two names which may be consistent with it share
+    /// at least one of these hashes.
+    ///
+    /// The first is always `hash`. The rest hash the letters of the surname
+    /// (or paternal surname), first all of them, then with each one left out
+    /// in turn, so there are two more than there are letters.
+    ///
+    /// ```
+    /// use human_name::Name;
+    ///
+    /// let hanson = Name::parse("James Hanson").unwrap().surname_typo_hashes();
+    /// let hansen = Name::parse("James Hansen").unwrap().surname_typo_hashes();
+    /// let hansson = Name::parse("James Hansson").unwrap().surname_typo_hashes();
+    /// assert!(hanson.iter().any(|h| hansen.contains(h)));
+    /// assert!(hanson.iter().any(|h| hansson.contains(h)));
+    /// ```
+    pub fn surname_typo_hashes(&self) -> Vec<u64> {
+        let letters = self.paternal_surname_letters();
+        let mut hashes = Vec::with_capacity(letters.len() + 2);
+        hashes.push(self.hash);
+
+        for skip in iter::once(None).chain((0..letters.len()).map(Some)) {
+            let mut s = DefaultHasher::new();
+            for (_, c) in letters.iter().enumerate().filter(|&(i, _)| Some(i) != skip) {
+                c.hash(&mut s);
+            }
+            hashes.push(s.finish());
+        }
+
+        hashes
+    }
+
     #[inline]
     fn surname_words(&self) -> usize {
         self.word_indices_in_text.len() - self.surname_index
@@ -485,10 +945,44 @@ impl Name {
         self.word_iter(self.surname_index..self.word_indices_in_text.len())
     }
 
+    // The whole surname, unless it can be split into paternal and maternal
+    // surnames
+    #[inline]
+    fn paternal_surname_iter(&self) -> Words {
+        match self.iberian_surname_split() {
+            Some((end, _)) => self.word_iter(self.surname_index..end),
+            None => self.surname_iter(),
+        }
+    }
+
+    fn paternal_surname_letters(&self) -> Vec<char> {
+        self.paternal_surname_iter()
+            .flat_map(|w| w.chars())
+            .flat_map(transliterate)
+            .filter_map(lowercase_if_alpha)
+            .collect()
+    }
+
+    #[inline]
+    fn maternal_surname_iter(&self) -> Option<Words> {
+        self.iberian_surname_split()
+            .map(|(_, start)| self.word_iter(start..self.word_indices_in_text.len()))
+    }
+
+    fn iberian_surname_split(&self) -> Option<(usize, usize)> {
+        if !self.iberian_surnames {
+            return None;
+        }
+
+        surname::split_iberian_surname(&self.surnames())
+            .map(|(end, start)| (self.surname_index + end, self.surname_index + start))
+    }
+
     #[inline]
     fn middle_name_iter(&self) -> Option<Words> {
-        if self.surname_index > 1 {
-            Some(self.word_iter(1..self.surname_index))
+        let end = self.given_names_end();
+        if end > 1 {
+            Some(self.word_iter(1..end))
         } else {
             None
         }
@@ -496,7 +990,17 @@ impl Name {
 
     #[inline]
     fn given_iter(&self) -> Words {
-        self.word_iter(0..self.surname_index)
+        self.word_iter(0..self.given_names_end())
+    }
+
+    // Index of the word after the given and middle names, i.e. of the
+    // patronymic if it precedes the surname, otherwise of the surname
+    #[inline]
+    fn given_names_end(&self) -> usize {
+        match self.patronymic {
+            Some(ref range) if range.start < self.surname_index => range.start,
+            _ => self.surname_index,
+        }
     }
 
     #[inline]
@@ -506,6 +1010,44 @@ impl Name {
             indices: self.word_indices_in_text[range].iter(),
         }
     }
+
+    // Each given or middle name, or initial without a known name, in order
+    fn given_parts(&self) -> Vec<GivenPart> {
+        let mut words = self.given_iter();
+        let mut word_indices = self.word_indices_in_initials.iter().peekable();
+        let mut parts = Vec::with_capacity(self.initials.len());
+        let mut i = 0;
+
+        while i < self.initials.len() {
+            match word_indices.peek().cloned() {
+                Some(range) if range.start == i => {
+                    word_indices.next();
+                    parts.push(GivenPart {
+                        initials: &self.initials[range.clone()],
+                        word: words.next(),
+                    });
+                    i = range.end;
+                }
+                _ => {
+                    let len = self.initials[i..].chars().next().unwrap().len_utf8();
+                    parts.push(GivenPart {
+                        initials: &self.initials[i..i + len],
+                        word: None,
+                    });
+                    i += len;
+                }
+            }
+        }
+
+        parts
+    }
+}
+
+// A given or middle name, or an initial for which we don't know the name
+#[derive(Clone, Copy)]
+struct GivenPart<'a> {
+    initials: &'a str,
+    word: Option<&'a str>,
 }
 
 struct Words<'a> {
@@ -532,18 +1074,78 @@ impl<'a> DoubleEndedIterator for Words<'a> {
     }
 }
 
+// The words of a part of a name given on its own, each of which must be a
+// name or initials
+fn component_words<'a>(
+    text: &'a str,
+    trust_capitalization: bool,
+    location: Location,
+) -> Result<Vec<NamePart<'a>>, ParseError> {
+    let text = text.trim();
+    let words: Vec<NamePart> =
+        NamePart::all_from_text(text, trust_capitalization, location).collect();
+    if words.is_empty() {
+        Err(ParseError::EmptyComponent)
+    } else if words
+        .iter()
+        .any(|word| !word.is_namelike() && !word.is_initials())
+    {
+        Err(ParseError::UnexpectedWord)
+    } else {
+        Ok(words)
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
     use test::{black_box, Bencher};
 
+    #[test]
+    fn from_components() {
+        let name = Name::from_components("J.", &["Robert"], "van der Berg", None).unwrap();
+        assert_eq!("J. Robert van der Berg", name.display_full());
+        assert_eq!("van der Berg", name.surname());
+        assert!(name.goes_by_middle_name());
+        assert!(name.consistent_with(&Name::parse("Robert van der Berg").unwrap()));
+
+        let name = Name::from_components("J. Robert", &[], "Doe", Some("III")).unwrap();
+        assert_eq!("J. Robert Doe, III", name.display_full());
+        assert_eq!("JR", name.initials());
+
+        let name = Name::from_components("Mary Ann", &[], "Doe", None).unwrap();
+        assert_eq!(Name::parse("Mary Ann Doe").unwrap().hash, name.hash);
+        assert_eq!(None, name.middle_names());
+    }
+
+    #[test]
+    fn from_invalid_components() {
+        assert_eq!(
+            Err(ParseError::EmptyComponent),
+            Name::from_components("", &[], "Doe", None).map(|_| ())
+        );
+        assert_eq!(
+            Err(ParseError::EmptyComponent),
+            Name::from_components("Jane", &[""], "Doe", None).map(|_| ())
+        );
+        assert_eq!(
+            Err(ParseError::NoSurname),
+            Name::from_components("Jane", &[], "D.", None).map(|_| ())
+        );
+        assert_eq!(
+            Err(ParseError::UnexpectedWord),
+            Name::from_components("Jane", &[], "Doe", Some("Esq.")).map(|_| ())
+        );
+    }
+
     #[bench]
     fn initialize_struct_initial_surname(b: &mut Bencher) {
         let name = "J. Doe";
-        let (words, surname_index, generation) = parse::parse(&*name).unwrap();
+        let (words, surname_index, generation, titles) = parse::parse(&*name).unwrap();
         b.iter(|| {
             black_box(
-                Name::initialize_struct(&words, surname_index, generation, name.len()).byte_len(),
+                Name::initialize_struct(&words, surname_index, generation, &titles, name.len())
+                    .byte_len(),
             )
         })
     }
@@ -551,10 +1153,11 @@ mod tests {
     #[bench]
     fn initialize_struct_first_last(b: &mut Bencher) {
         let name = "John Doe";
-        let (words, surname_index, generation) = parse::parse(&*name).unwrap();
+        let (words, surname_index, generation, titles) = parse::parse(&*name).unwrap();
         b.iter(|| {
             black_box(
-                Name::initialize_struct(&words, surname_index, generation, name.len()).byte_len(),
+                Name::initialize_struct(&words, surname_index, generation, &titles, name.len())
+                    .byte_len(),
             )
         })
     }
@@ -562,10 +1165,11 @@ mod tests {
     #[bench]
     fn initialize_struct_complex(b: &mut Bencher) {
         let name = "John Allen Q.R. de la MacDonald Jr.";
-        let (words, surname_index, generation) = parse::parse(&*name).unwrap();
+        let (words, surname_index, generation, titles) = parse::parse(&*name).unwrap();
         b.iter(|| {
             black_box(
-                Name::initialize_struct(&words, surname_index, generation, name.len()).byte_len(),
+                Name::initialize_struct(&words, surname_index, generation, &titles, name.len())
+                    .byte_len(),
             )
         })
     }
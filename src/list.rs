@@ -0,0 +1,320 @@
+use super::namepart::{Location, NamePart};
+use super::suffix;
+use super::surname::is_surname_prefix;
+use super::title;
+use super::utils::is_mixed_case;
+use super::Name;
+use smallvec::SmallVec;
+use unicode_segmentation::UnicodeSegmentation;
+
+/// People parsed from a string naming more than one of them.
+///
+/// Returned by `Name::parse_list`.
+#[derive(Clone, Debug)]
+pub struct NameList {
+    pub names: Vec<Name>,
+    /// Whether the list ended with "et al", meaning more people were omitted.
+    pub truncated: bool,
+}
+
+impl Name {
+    /// Parses a string naming any number of people, such as an author list
+    /// or a couple, into the names of each person, skipping any that can't
+    /// be parsed.
+    ///
+    /// See `parse_list` for details, and to find out whether the list was
+    /// truncated with "et al".
+    ///
+    /// ```
+    /// use human_name::Name;
+    ///
+    /// let names = Name::parse_many("Smith J, Doe JA, and Lee K");
+    /// assert_eq!(3, names.len());
+    /// assert_eq!("J. Smith", names[0].display_full());
+    /// assert_eq!("J. A. Doe", names[1].display_full());
+    /// assert_eq!("K. Lee", names[2].display_full());
+    ///
+    /// let names = Name::parse_many("Jane and John Doe");
+    /// assert_eq!("Jane Doe", names[0].display_full());
+    /// assert_eq!("John Doe", names[1].display_full());
+    ///
+    /// let names = Name::parse_many("Doe, Jane; Smith, John");
+    /// assert_eq!("Jane Doe", names[0].display_full());
+    /// assert_eq!("John Smith", names[1].display_full());
+    /// ```
+    pub fn parse_many(text: &str) -> Vec<Name> {
+        Name::parse_list(text).names
+    }
+
+    /// Parses a string naming any number of people, such as an author list
+    /// or a couple, and reports whether the list ended with "et al".
+    ///
+    /// People may be separated by semicolons, commas, "and" or "&". Commas
+    /// are also allowed within each person's name, as in "Doe, Jane, Smith,
+    /// John" or "Doe, Jane, Jr.". Author-list entries with trailing initials,
+    /// as in "Doe JA", are treated as surname and initials.
+    ///
+    /// When a person is named only by a given name or only by titles, as in
+    /// "Jane and John Doe" or "Mr. & Mrs. John Doe", the rest of the name is
+    /// taken from the following person.
+    ///
+    /// ```
+    /// use human_name::Name;
+    ///
+    /// let list = Name::parse_list("Mr. & Mrs. John Doe");
+    /// assert!(!list.truncated);
+    /// assert_eq!(2, list.names.len());
+    /// assert_eq!(vec!["Mr."], list.names[0].titles().unwrap().to_vec());
+    /// assert_eq!(vec!["Mrs."], list.names[1].titles().unwrap().to_vec());
+    /// assert_eq!("John Doe", list.names[1].display_full());
+    ///
+    /// let list = Name::parse_list("Jane Doe, John Smith, Jr., et al.");
+    /// assert!(list.truncated);
+    /// assert_eq!(2, list.names.len());
+    /// assert_eq!("John Smith, Jr.", list.names[1].display_full());
+    /// ```
+    pub fn parse_list(text: &str) -> NameList {
+        let (text, truncated) = strip_et_al(text);
+
+        let mut people: Vec<String> = Vec::new();
+        for group in text.split(';') {
+            for chunk in split_on_conjunctions(group) {
+                group_comma_separated_parts(chunk, &mut people);
+            }
+        }
+
+        let mut names = Vec::with_capacity(people.len());
+        let mut incomplete: SmallVec<[&str; 2]> = SmallVec::new();
+
+        for person in &people {
+            match Name::parse(person) {
+                Some(name) => {
+                    // Fill in anyone who was waiting for the rest of their name
+                    for partial in incomplete.drain() {
+                        if let Some(name) = complete_with(partial, &name) {
+                            names.push(name);
+                        }
+                    }
+                    names.push(name);
+                }
+                None => incomplete.push(person),
+            }
+        }
+
+        NameList { names, truncated }
+    }
+}
+
+// E.g. "Jane" or "Mrs." followed by "John Doe"
+fn complete_with(partial: &str, name: &Name) -> Option<Name> {
+    Name::parse(&format!("{} {}", partial, name.surname()))
+        .or_else(|| Name::parse(&format!("{} {}", partial, name.display_full())))
+}
+
+fn strip_et_al(text: &str) -> (&str, bool) {
+    let trimmed = text.trim_end_matches(|c: char| c == '.' || c == ',' || c.is_whitespace());
+
+    let stripped = strip_word_from_end(trimmed, "al")
+        .map(|rest| rest.trim_end_matches(|c: char| c == '.' || c.is_whitespace()))
+        .and_then(|rest| strip_word_from_end(rest, "et"));
+
+    match stripped {
+        Some(rest) => (rest, true),
+        None => (text, false),
+    }
+}
+
+fn strip_word_from_end<'a>(text: &'a str, word: &str) -> Option<&'a str> {
+    if text.len() < word.len() || !text.is_char_boundary(text.len() - word.len()) {
+        return None;
+    }
+
+    let (rest, end) = text.split_at(text.len() - word.len());
+    if end.eq_ignore_ascii_case(word) && !rest.ends_with(char::is_alphanumeric) {
+        Some(rest)
+    } else {
+        None
+    }
+}
+
+fn split_on_conjunctions(text: &str) -> SmallVec<[&str; 4]> {
+    let mut chunks = SmallVec::new();
+    let mut start = 0;
+
+    for (i, word) in text.split_word_bound_indices() {
+        if word == "&" || word.eq_ignore_ascii_case("and") {
+            chunks.push(&text[start..i]);
+            start = i + word.len();
+        }
+    }
+    chunks.push(&text[start..]);
+
+    chunks
+}
+
+// Groups comma-separated parts into people, e.g. "Doe, Jane, Smith, John"
+// into "Doe, Jane" and "Smith, John", or "Smith J, Doe JA" into "Smith, J"
+// and "Doe, JA"
+fn group_comma_separated_parts(chunk: &str, people: &mut Vec<String>) {
+    let parts: SmallVec<[&str; 6]> = chunk
+        .split(',')
+        .map(str::trim)
+        .filter(|part| !part.is_empty())
+        .collect();
+
+    let mut i = 0;
+    while i < parts.len() {
+        let part = parts[i];
+        i += 1;
+
+        // Suffixes and titles belong to the previous person (e.g. "Jr." or "MD")
+        if is_postfix_only(part) {
+            if let Some(person) = people.last_mut() {
+                person.push_str(", ");
+                person.push_str(part);
+                continue;
+            }
+        }
+
+        if let Some(person) = reorder_trailing_initials(part) {
+            people.push(person);
+            continue;
+        }
+
+        // A part which isn't a full name alone, or followed by one which
+        // isn't, is a surname followed by given names or initials
+        if i < parts.len()
+            && !is_postfix_only(parts[i])
+            && (Name::parse(part).is_none() || Name::parse(parts[i]).is_none())
+        {
+            people.push(format!("{}, {}", part, parts[i]));
+            i += 1;
+            continue;
+        }
+
+        people.push(part.to_string());
+    }
+}
+
+fn is_postfix_only(part: &str) -> bool {
+    let words: SmallVec<[NamePart; 3]> =
+        NamePart::all_from_text(part, is_mixed_case(part), Location::End).collect();
+
+    if words.is_empty() {
+        false
+    } else if words
+        .iter()
+        .all(|word| suffix::generation_from_suffix(word, false).is_some())
+    {
+        true
+    } else {
+        // E.g. "Mr." in "Mr. & Mrs. John Doe"
+        !title::is_prefix_title(&words)
+            && words
+                .iter()
+                .all(|word| title::is_postfix_title(word, false))
+    }
+}
+
+// "Doe JA" or "van der Berg J" (but not "Jane Doe MD", or "Jane SMITH", where
+// the capitalized surname has too many letters and vowels to be initials)
+fn reorder_trailing_initials(part: &str) -> Option<String> {
+    let words: SmallVec<[NamePart; 4]> =
+        NamePart::all_from_text(part, is_mixed_case(part), Location::End).collect();
+
+    if words.len() < 2 {
+        return None;
+    }
+
+    let (last, surname) = words.split_last().unwrap();
+    let looks_like_initials = last.is_initials()
+        && !last.word.chars().any(char::is_lowercase)
+        && (last.counts.alpha <= 2 || last.counts.ascii_vowels == 0);
+
+    if looks_like_initials
+        && surname.last().unwrap().is_namelike()
+        && surname[..surname.len() - 1].iter().all(is_surname_prefix)
+    {
+        let surname_end = surname.last().unwrap();
+        let surname_len =
+            surname_end.word.as_ptr() as usize + surname_end.word.len() - part.as_ptr() as usize;
+        Some(format!("{}, {}", &part[..surname_len], last.word))
+    } else {
+        None
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn et_al() {
+        assert_eq!(("Jane Doe, ", true), strip_et_al("Jane Doe, et al."));
+        assert_eq!(("Jane Doe ", true), strip_et_al("Jane Doe et. al"));
+        assert_eq!(("Jane Doe", false), strip_et_al("Jane Doe"));
+        assert_eq!(("Jane Metal", false), strip_et_al("Jane Metal"));
+    }
+
+    #[test]
+    fn author_list() {
+        let list = Name::parse_list("Smith J, Doe JA, and Lee K, et al");
+        assert!(list.truncated);
+        assert_eq!(3, list.names.len());
+        assert_eq!("Smith", list.names[0].surname());
+        assert_eq!("JA", list.names[1].initials());
+        assert_eq!("Lee", list.names[2].surname());
+    }
+
+    #[test]
+    fn sort_order() {
+        let names = Name::parse_many("Doe, Jane, de la Hoya, Oscar, Smith, John, Jr.");
+        assert_eq!(3, names.len());
+        assert_eq!("Jane Doe", names[0].display_full());
+        assert_eq!("Oscar de la Hoya", names[1].display_full());
+        assert_eq!("John Smith, Jr.", names[2].display_full());
+    }
+
+    #[test]
+    fn couples() {
+        let names = Name::parse_many("Jane & John Doe");
+        assert_eq!(2, names.len());
+        assert_eq!("Jane Doe", names[0].display_full());
+        assert_eq!("John Doe", names[1].display_full());
+
+        let names = Name::parse_many("Jane Smith and John Doe");
+        assert_eq!("Jane Smith", names[0].display_full());
+        assert_eq!("John Doe", names[1].display_full());
+
+        let names = Name::parse_many("Dr. and Mrs. John Doe");
+        assert_eq!(2, names.len());
+        assert_eq!(vec!["Dr."], names[0].titles().unwrap().to_vec());
+        assert_eq!("John Doe", names[0].display_full());
+    }
+
+    #[test]
+    fn single() {
+        let names = Name::parse_many("Jane Doe");
+        assert_eq!(1, names.len());
+        assert_eq!("Jane Doe", names[0].display_full());
+
+        assert!(Name::parse_many("").is_empty());
+    }
+
+    #[test]
+    fn capitalized_surname() {
+        let names = Name::parse_many("Jane SMITH");
+        assert_eq!(1, names.len());
+        assert_eq!("Jane Smith", names[0].display_full());
+
+        let names = Name::parse_many("Mary DOE");
+        assert_eq!(1, names.len());
+        assert_eq!("Mary Doe", names[0].display_full());
+
+        let names = Name::parse_many("Doe JA, van der Berg J, Smith JRR");
+        assert_eq!(3, names.len());
+        assert_eq!("Doe", names[0].surname());
+        assert_eq!("van der Berg", names[1].surname());
+        assert_eq!("JRR", names[2].initials());
+    }
+}
@@ -229,6 +229,36 @@ mod bench {
         })
     }
 
+    #[bench]
+    fn bench_cluster_many(b: &mut Bencher) {
+        let f = File::open("tests/benchmark-names.txt").ok().unwrap();
+        let reader = BufReader::new(f);
+        let names: Vec<Name> = reader
+            .lines()
+            .filter_map(|l| Name::parse(&l.ok().unwrap()))
+            .collect();
+
+        b.iter(|| black_box(human_name::cluster(&names).len()))
+    }
+
+    #[bench]
+    fn bench_index_many(b: &mut Bencher) {
+        let f = File::open("tests/benchmark-names.txt").ok().unwrap();
+        let reader = BufReader::new(f);
+        let index: human_name::NameIndex = reader
+            .lines()
+            .filter_map(|l| Name::parse(&l.ok().unwrap()))
+            .collect();
+
+        b.iter(|| {
+            let mut matches = 0;
+            for name in index.names() {
+                matches += index.find_consistent(name).count();
+            }
+            black_box(matches)
+        })
+    }
+
     #[bench]
     fn bench_web_match(b: &mut Bencher) {
         let name = Name::parse("Jane Doe").unwrap();
@@ -0,0 +1,222 @@
+use super::namepart::NamePart;
+use super::parse::Titles;
+use super::utils::to_ascii_letter;
+use super::{GivenPart, Name};
+use smallvec::SmallVec;
+use std::borrow::Cow;
+
+impl<'a> GivenPart<'a> {
+    fn same_initials(&self, other: &GivenPart) -> bool {
+        self.initials
+            .chars()
+            .filter_map(to_ascii_letter)
+            .eq(other.initials.chars().filter_map(to_ascii_letter))
+    }
+
+    fn fuller(self, other: GivenPart<'a>) -> GivenPart<'a> {
+        match (self.word, other.word) {
+            (Some(mine), Some(theirs)) if theirs.chars().count() > mine.chars().count() => other,
+            (None, Some(_)) => other,
+            _ => self,
+        }
+    }
+}
+
+impl Name {
+    /// Combines this name with another consistent with it (see
+    /// `consistent_with`) into the most complete representation of both, or
+    /// returns `None` if they aren't consistent.
+    ///
+    /// Takes the fuller of each given or middle name (e.g. "Daniel" over
+    /// "Dan", or any name over a bare initial), the longer surname (e.g. "del
+    /// Río Gayo" over "Gayo"), and the suffix, patronymic, titles and nickname
+    /// of either, preferring this name's where both have one.
+    ///
+    /// ```
+    /// use human_name::Name;
+    ///
+    /// let a = Name::parse("Daniel A. del Rio Gayo").unwrap();
+    /// let b = Name::parse("Dan Alberto Gayo, Jr.").unwrap();
+    ///
+    /// let merged = a.merge(&b).unwrap();
+    /// assert_eq!("Daniel Alberto del Rio Gayo, Jr.", merged.display_full());
+    /// assert!(merged.consistent_with(&a));
+    /// assert!(merged.consistent_with(&b));
+    ///
+    /// let c = Name::parse("John Gayo").unwrap();
+    /// assert!(a.merge(&c).is_none());
+    /// ```
+    pub fn merge(&self, other: &Name) -> Option<Name> {
+        if !self.consistent_with(other) {
+            return None;
+        }
+
+        let mut words: Vec<NamePart> = self
+            .merged_given_parts(other)
+            .into_iter()
+            .map(|part| match part.word {
+                Some(word) => NamePart::from_name(word),
+                None => NamePart::from_initials(part.initials),
+            })
+            .collect();
+
+        // Unless the patronymic doubles as the surname, as with Icelandic names
+        let with_patronymic = [self, other]
+            .iter()
+            .cloned()
+            .find(|name| match name.patronymic {
+                Some(ref range) => range.end <= name.surname_index,
+                None => false,
+            });
+        if let Some(name) = with_patronymic {
+            let range = name.patronymic.clone().unwrap();
+            words.extend(name.word_iter(range).map(NamePart::from_name));
+        }
+
+        let surname_index = words.len();
+        let with_surname = if (other.surname_words(), other.surname().chars().count())
+            > (self.surname_words(), self.surname().chars().count())
+        {
+            other
+        } else {
+            self
+        };
+        words.extend(with_surname.surname_iter().map(NamePart::from_name));
+
+        let titles = Titles {
+            prefix: borrowed_titles(&self.prefix_titles, &other.prefix_titles),
+            postfix: borrowed_titles(&self.postfix_titles, &other.postfix_titles),
+        };
+
+        let mut name = Name::initialize_struct(
+            &words,
+            surname_index,
+            self.generation_from_suffix.or(other.generation_from_suffix),
+            &titles,
+            self.text.len() + other.text.len(),
+        );
+
+        name.nickname = self.nickname.clone().or_else(|| other.nickname.clone());
+        name.iberian_surnames = self.iberian_surnames || other.iberian_surnames;
+        name.initialize_hash();
+
+        Some(name)
+    }
+
+    // Aligns the given and middle names of the less complete name with those
+    // of the more complete, by their initials, keeping the fuller of each pair
+    fn merged_given_parts<'a>(&'a self, other: &'a Name) -> Vec<GivenPart<'a>> {
+        let my_completeness = (
+            self.initials.chars().count(),
+            self.word_indices_in_initials.len(),
+        );
+        let their_completeness = (
+            other.initials.chars().count(),
+            other.word_indices_in_initials.len(),
+        );
+
+        let (more, less) = if my_completeness >= their_completeness {
+            (self.given_parts(), other.given_parts())
+        } else {
+            (other.given_parts(), self.given_parts())
+        };
+
+        let mut their_parts = less.iter().cloned().peekable();
+        let mut merged = Vec::with_capacity(more.len());
+
+        for (i, &my_part) in more.iter().enumerate() {
+            let aligned = match their_parts.peek() {
+                Some(their_part) => {
+                    my_part.same_initials(their_part)
+                        || (
+                            // Nicknames needn't share an initial, as in "Bill"
+                            // and "William"
+                            i == 0
+                                && my_part.word.is_some()
+                                && their_part.word.is_some()
+                                && !more[1..].iter().any(|part| part.same_initials(their_part))
+                        )
+                }
+                None => false,
+            };
+
+            if aligned {
+                merged.push(my_part.fuller(their_parts.next().unwrap()));
+            } else {
+                merged.push(my_part);
+            }
+        }
+
+        merged
+    }
+}
+
+fn borrowed_titles<'a>(
+    mine: &'a [Box<str>],
+    theirs: &'a [Box<str>],
+) -> SmallVec<[Cow<'a, str>; 2]> {
+    let titles = if mine.is_empty() { theirs } else { mine };
+    titles.iter().map(|title| Cow::Borrowed(&**title)).collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn merge(a: &str, b: &str) -> Option<String> {
+        let a = Name::parse(a).unwrap();
+        let b = Name::parse(b).unwrap();
+        a.merge(&b).map(|name| name.display_full().to_string())
+    }
+
+    #[test]
+    fn given_names() {
+        assert_eq!(Some("Jane Doe".into()), merge("J. Doe", "Jane Doe"));
+        assert_eq!(
+            Some("Jane Mary Doe".into()),
+            merge("Jane M. Doe", "J. Mary Doe")
+        );
+        assert_eq!(Some("Jane M. Doe".into()), merge("Jane Doe", "J. M. Doe"));
+        assert_eq!(
+            Some("Kenneth Doe".into()),
+            merge("Kenny Doe", "Kenneth Doe")
+        );
+        assert_eq!(
+            Some("William Gates".into()),
+            merge("Bill Gates", "William Gates")
+        );
+        assert_eq!(
+            Some("Thomas Boone Pickens".into()),
+            merge("T Boone Pickens", "Thomas Boone Pickens")
+        );
+        assert_eq!(
+            Some("Al Tomas Smith".into()),
+            merge("Tomas Smith", "Al Tomas Smith")
+        );
+    }
+
+    #[test]
+    fn other_parts() {
+        let a = Name::parse("Dr. Robert 'Bob' Doe").unwrap();
+        let b = Name::parse("R. Doe III, MD").unwrap();
+        let merged = b.merge(&a).unwrap();
+        assert_eq!("Robert Doe, III", merged.display_full());
+        assert_eq!(Some("Bob"), merged.nickname());
+        assert_eq!(vec!["Dr."], merged.titles().unwrap().to_vec());
+        assert_eq!(vec!["MD"], merged.postfix_titles().unwrap().to_vec());
+        assert_eq!(a.hash, merged.hash);
+
+        let a = Name::parse("Ivan Petrov").unwrap();
+        let b = Name::parse("I. Ivanovich Petrov").unwrap();
+        let merged = a.merge(&b).unwrap();
+        assert_eq!("Ivan Ivanovich Petrov", merged.display_full());
+        assert_eq!("Ivanovich", merged.patronymic().unwrap());
+        assert_eq!("I", merged.initials());
+    }
+
+    #[test]
+    fn inconsistent() {
+        assert_eq!(None, merge("Jane Doe", "John Doe"));
+        assert_eq!(None, merge("Jane Doe", "Jane Smith"));
+    }
+}
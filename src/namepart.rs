@@ -70,6 +70,25 @@ impl<'a> NamePart<'a> {
         NamePart::from_word_and_counts(word, categorize_chars(word), trust_capitalization, location)
     }
 
+    // For a word already known to be a namecased name, e.g. from a parsed
+    // `Name`, so it isn't recategorized or namecased again
+    pub fn from_name(word: &str) -> NamePart {
+        NamePart {
+            word,
+            counts: categorize_chars(word),
+            category: Category::Name(Cow::Borrowed(word)),
+        }
+    }
+
+    // For initials already known to be initials, e.g. from a parsed `Name`
+    pub fn from_initials(word: &str) -> NamePart {
+        NamePart {
+            word,
+            counts: categorize_chars(word),
+            category: Category::Initials,
+        }
+    }
+
     #[allow(clippy::if_same_then_else)]
     pub fn from_word_and_counts(
         word: &str,
@@ -132,7 +151,11 @@ impl<'a> NamePart<'a> {
             }
         } else if chars <= 5 && trust_capitalization && all_upper {
             Category::Initials
-        } else if chars == 2 && !trust_capitalization && !TWO_LETTER_GIVEN_NAMES.contains(word) {
+        } else if chars == 2
+            && !trust_capitalization
+            && !word.chars().all(is_hangul_jamo)
+            && !TWO_LETTER_GIVEN_NAMES.contains(word)
+        {
             Category::Initials
         } else {
             Category::Name(namecased())
@@ -323,6 +346,12 @@ mod tests {
         assert!(NamePart::from_word("JEM", false, Location::Start).is_namelike());
     }
 
+    #[test]
+    fn two_jamo() {
+        // A single Hangul syllable, in NFKD form
+        assert!(NamePart::from_word("\u{110b}\u{1175}", false, Location::End).is_namelike());
+    }
+
     #[test]
     fn two_letters() {
         assert!(NamePart::from_word("Al", true, Location::Start).is_namelike());
@@ -331,6 +360,8 @@ mod tests {
         assert!(NamePart::from_word("At", true, Location::Start).is_namelike());
         assert!(NamePart::from_word("AT", true, Location::Start).is_initials());
         assert!(NamePart::from_word("AT", false, Location::Start).is_initials());
+        assert!(NamePart::from_word("ÉL", false, Location::Start).is_initials());
+        assert!(NamePart::from_word("Øy", false, Location::End).is_initials());
     }
 
     #[bench]
@@ -1,7 +1,16 @@
+use super::cognate::are_cognates;
+use super::comparison::MatchOptions;
+use super::namecase::namecase;
 use super::utils::*;
 use phf;
+use smallvec::SmallVec;
 use std::borrow::Cow;
+use std::collections::{HashMap, HashSet};
+use std::error::Error;
+use std::fmt;
 use std::iter;
+use std::ops::Range;
+use std::slice;
 
 // Returns tuple (close_char, must_precede_whitespace)
 fn expected_close_char_if_opens_nickname(
@@ -48,9 +57,60 @@ fn strip_from_index(nick_start_ix: usize, prev_char: char) -> usize {
     }
 }
 
-// Optimized for the case where there is no nickname, and secondarily for the
-// case where there is only one. Two or more probably means bad input.
+fn nickname_text(nickname: &str) -> Option<&str> {
+    let nickname = nickname.trim();
+    if nickname.chars().any(char::is_alphabetic) {
+        Some(nickname)
+    } else {
+        None
+    }
+}
+
+// Captured nicknames are namecased, like the rest of the name, unless we can
+// trust the capitalization they were given with
+pub fn namecase_nickname(nickname: &str) -> Cow<str> {
+    if is_mixed_case(nickname) {
+        Cow::Borrowed(nickname)
+    } else {
+        let words: Vec<String> = nickname
+            .split(' ')
+            .filter(|w| !w.is_empty())
+            .map(|w| namecase(w, w.chars().all(is_ascii_alphabetic), false))
+            .collect();
+        Cow::Owned(words.join(" "))
+    }
+}
+
 pub fn strip_nickname(input: &str) -> Cow<str> {
+    strip_and_capture_nickname(input).0
+}
+
+pub fn strip_and_capture_nickname(input: &str) -> (Cow<str>, Option<&str>) {
+    let (kept, nickname) = find_nicknames(input);
+    let stripped = match kept.len() {
+        0 => Cow::Borrowed(""),
+        1 => Cow::Borrowed(&input[kept[0].clone()]),
+        _ => Cow::Owned(kept.iter().map(|range| &input[range.clone()]).collect()),
+    };
+    (stripped, nickname)
+}
+
+// The ranges of the input left once nicknames are stripped, and the first
+// nickname, if any (so the stripped text can be mapped back to the input)
+pub fn find_nicknames(input: &str) -> (SmallVec<[Range<usize>; 2]>, Option<&str>) {
+    let mut kept = SmallVec::new();
+    let nickname = keep_outside_nicknames(input, 0, &mut kept);
+    (kept, nickname)
+}
+
+// Optimized for the case where there is no nickname, and secondarily for the
+// case where there is only one. Two or more probably means bad input, so we
+// strip them all but only capture the first.
+fn keep_outside_nicknames<'a>(
+    input: &'a str,
+    offset: usize,
+    kept: &mut SmallVec<[Range<usize>; 2]>,
+) -> Option<&'a str> {
     let mut nick_start_ix = None;
     let mut nick_open_char = '\0';
     let mut expected_close_char = '\0';
@@ -69,13 +129,19 @@ pub fn strip_nickname(input: &str) -> Cow<str> {
             }
         } else if c == expected_close_char {
             let j = i + c.len_utf8();
+            let nickname =
+                nickname_text(&input[nick_start_ix.unwrap() + nick_open_char.len_utf8()..i]);
             if j >= input.len() {
-                return Cow::Borrowed(&input[0..nick_start_ix.unwrap()]);
+                keep(kept, offset..offset + nick_start_ix.unwrap());
+                return nickname;
             } else if !must_precede_whitespace || input[j..].starts_with(' ') {
                 let strip_from = strip_from_index(nick_start_ix.unwrap(), prev_char);
-                return Cow::Owned(input[0..strip_from].to_string() + &strip_nickname(&input[j..]));
+                keep(kept, offset..offset + strip_from);
+                let later_nickname = keep_outside_nicknames(&input[j..], offset + j, kept);
+                return nickname.or(later_nickname);
             } else {
-                return Cow::Owned(input[0..i].to_string() + &strip_nickname(&input[i..]));
+                keep(kept, offset..offset + i);
+                return keep_outside_nicknames(&input[i..], offset + i, kept);
             }
         }
     }
@@ -85,7 +151,8 @@ pub fn strip_nickname(input: &str) -> Cow<str> {
             // When there's, e.g., an opening parens, but no closing parens, strip the
             // rest of the string
             let strip_from = strip_from_index(i, prev_char);
-            return Cow::Borrowed(&input[0..strip_from]);
+            keep(kept, offset..offset + strip_from);
+            return None;
         } else {
             let j = i + nick_open_char.len_utf8();
             // Otherwise, even if there's an unmatched opening quote, don't
@@ -96,24 +163,183 @@ pub fn strip_nickname(input: &str) -> Cow<str> {
             // string for actual nicknames, whose opening character we might
             // have missed while looking for the first closing character
             if j >= input.len() {
-                return Cow::Borrowed(input);
+                keep(kept, offset..offset + input.len());
+                return None;
             } else {
-                return Cow::Owned(input[0..j].to_string() + &strip_nickname(&input[j..]));
+                keep(kept, offset..offset + j);
+                return keep_outside_nicknames(&input[j..], offset + j, kept);
+            }
+        }
+    }
+
+    keep(kept, offset..offset + input.len());
+    None
+}
+
+// Joins ranges which turn out to be contiguous, as when a quote didn't
+// open a nickname after all
+fn keep(kept: &mut SmallVec<[Range<usize>; 2]>, range: Range<usize>) {
+    if range.start == range.end {
+        return;
+    }
+    if let Some(last) = kept.last_mut() {
+        if last.end == range.start {
+            last.end = range.end;
+            return;
+        }
+    }
+    kept.push(range);
+}
+
+/// Nicknames and the names they may be short for, to match given names by
+/// in addition to, or instead of, our built-in tables.
+///
+/// Use with `consistent_with_opts` by setting `MatchOptions::nicknames`.
+///
+/// ```
+/// use human_name::{MatchOptions, Name, NicknameDb};
+///
+/// let mut nicknames = NicknameDb::new();
+/// nicknames.load("# nickname,name\nTiko,Teodoro\nBeto\tRoberto\n").unwrap();
+///
+/// let options = MatchOptions {
+///     nicknames: Some(&nicknames),
+///     ..Default::default()
+/// };
+///
+/// let tiko = Name::parse("Tiko Petrov").unwrap();
+/// let teodoro = Name::parse("Teodoro Petrov").unwrap();
+/// assert!(!tiko.consistent_with(&teodoro));
+/// assert!(tiko.consistent_with_opts(&teodoro, &options));
+///
+/// // Built-in nicknames still match, unless the tables are replaced
+/// let bill = Name::parse("Bill Gates").unwrap();
+/// let william = Name::parse("William Gates").unwrap();
+/// assert!(bill.consistent_with_opts(&william, &options));
+///
+/// let replaced = NicknameDb::without_builtin();
+/// let options = MatchOptions {
+///     nicknames: Some(&replaced),
+///     ..Default::default()
+/// };
+/// assert!(!bill.consistent_with_opts(&william, &options));
+/// ```
+#[derive(Clone, Debug, Default, Eq, PartialEq)]
+pub struct NicknameDb {
+    // Keyed by the lowercase nickname
+    names_by_nick: HashMap<String, Vec<String>>,
+    without_builtin: bool,
+}
+
+impl NicknameDb {
+    /// An empty dictionary, which adds to our built-in tables.
+    pub fn new() -> NicknameDb {
+        Default::default()
+    }
+
+    /// An empty dictionary, which replaces our built-in tables (including
+    /// nicknames matched by their first syllables, like "Abby" for
+    /// "Abigail"). Prefixes and diminutives, like "Dan" or "Danny" for
+    /// "Daniel", are still matched by rule.
+    pub fn without_builtin() -> NicknameDb {
+        NicknameDb {
+            without_builtin: true,
+            ..Default::default()
+        }
+    }
+
+    /// Adds a nickname for a name. Both are transliterated to ASCII, and
+    /// namecased unless given in mixed case.
+    pub fn insert(&mut self, nickname: &str, name: &str) {
+        let name = namecase_nickname(&to_ascii(name)).into_owned();
+        let names = self
+            .names_by_nick
+            .entry(to_ascii(nickname).to_lowercase())
+            .or_insert_with(Vec::new);
+
+        if !names.contains(&name) {
+            names.push(name);
+        }
+    }
+
+    /// Adds nicknames from text with one nickname and name per line,
+    /// separated by a comma or tab, as in a CSV or TSV file. Blank lines and
+    /// lines starting with `#` are skipped.
+    ///
+    /// If any other line isn't a nickname and name, adds nothing, and
+    /// returns an error with the first such line.
+    pub fn load(&mut self, pairs: &str) -> Result<(), NicknameDbError> {
+        let mut parsed = Vec::new();
+
+        for (i, line) in pairs.lines().enumerate() {
+            let line = line.trim();
+            if line.is_empty() || line.starts_with('#') {
+                continue;
+            }
+
+            let separator = if line.contains('\t') { '\t' } else { ',' };
+            let fields: Vec<&str> = line.split(separator).map(str::trim).collect();
+
+            match &fields[..] {
+                &[nickname, name] if !nickname.is_empty() && !name.is_empty() => {
+                    parsed.push((nickname, name));
+                }
+                _ => return Err(NicknameDbError { line: i + 1 }),
             }
         }
+
+        for (nickname, name) in parsed {
+            self.insert(nickname, name);
+        }
+
+        Ok(())
     }
 
-    Cow::Borrowed(input)
+    fn get(&self, nickname: &str) -> Option<&[String]> {
+        if self.names_by_nick.is_empty() {
+            None
+        } else {
+            self.names_by_nick
+                .get(&nickname.to_lowercase())
+                .map(|names| &names[..])
+        }
+    }
 }
 
+/// A line passed to `NicknameDb::load` which isn't a nickname and a name
+/// separated by a comma or tab.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub struct NicknameDbError {
+    /// Counting from one.
+    pub line: usize,
+}
+
+impl fmt::Display for NicknameDbError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "expected a nickname and a name on line {}", self.line)
+    }
+}
+
+impl Error for NicknameDbError {}
+
 struct NameVariants<'a> {
     original: &'a str,
     direct_variants: Option<&'a phf::Set<&'static str>>,
     prefix_variants: Option<&'a phf::Set<&'static str>>,
+    custom_variants: Option<&'a [String]>,
 }
 
 impl<'a> NameVariants<'a> {
-    pub fn for_name(name: &'a str) -> NameVariants<'a> {
+    pub fn for_name(name: &'a str, nicknames: Option<&'a NicknameDb>) -> NameVariants<'a> {
+        if nicknames.map_or(false, |db| db.without_builtin) {
+            return NameVariants {
+                original: name,
+                direct_variants: None,
+                prefix_variants: None,
+                custom_variants: nicknames.and_then(|db| db.get(name)),
+            };
+        }
+
         NameVariants {
             original: name,
             direct_variants: NAMES_BY_IRREGULAR_NICK.get(name),
@@ -126,6 +352,7 @@ impl<'a> NameVariants<'a> {
                     None
                 }
             },
+            custom_variants: nicknames.and_then(|db| db.get(name)),
         }
     }
 
@@ -134,6 +361,7 @@ impl<'a> NameVariants<'a> {
             original: iter::once(self.original),
             direct_variants: self.direct_variants.map(|names| names.iter()),
             prefix_variants: self.prefix_variants.map(|names| names.iter()),
+            custom_variants: self.custom_variants.map(|names| names.iter()),
         }
     }
 }
@@ -142,6 +370,7 @@ struct NameVariantIter<'a> {
     original: iter::Once<&'a str>,
     direct_variants: Option<phf::set::Iter<'a, &'static str>>,
     prefix_variants: Option<phf::set::Iter<'a, &'static str>>,
+    custom_variants: Option<slice::Iter<'a, String>>,
 }
 
 impl<'a> Iterator for NameVariantIter<'a> {
@@ -164,30 +393,253 @@ impl<'a> Iterator for NameVariantIter<'a> {
             }
         }
 
+        if let Some(ref mut iter) = self.custom_variants {
+            if let Some(name) = iter.next() {
+                return Some(name);
+            }
+        }
+
         None
     }
 }
 
-pub fn have_matching_variants(original_a: &str, original_b: &str) -> bool {
+// The name transliterated to ASCII, followed by any variants of it in our
+// nickname tables
+pub fn variants(name: &str) -> Vec<String> {
+    let name = to_ascii(name);
+    NameVariants::for_name(&*name, None)
+        .iter_with_original()
+        .map(str::to_string)
+        .collect()
+}
+
+/// Variants of a given name which `consistent_with` would match with it,
+/// to expand searches: formal names it may be short for, nicknames for it
+/// or for those names from our tables, and diminutives and final syllables
+/// which our rules would match, as in "Willie" and "Liam" for "William".
+///
+/// The name itself comes first, transliterated to ASCII. Prefixes, like
+/// "Will" for "William", also match but aren't listed.
+///
+/// ```
+/// use human_name::variants_of;
+///
+/// let variants: Vec<String> = variants_of("William").collect();
+/// assert_eq!("William", variants[0]);
+/// assert!(variants.contains(&"Bill".to_string()));
+/// assert!(variants.contains(&"Billy".to_string()));
+/// assert!(variants.contains(&"Willie".to_string()));
+/// assert!(variants.contains(&"Liam".to_string()));
+///
+/// let variants: Vec<String> = variants_of("Bob").collect();
+/// assert!(variants.contains(&"Robert".to_string()));
+/// assert!(variants.contains(&"Robby".to_string()));
+/// ```
+pub fn variants_of(name: &str) -> impl Iterator<Item = String> {
+    let name = namecase_nickname(&to_ascii(name)).into_owned();
+    let name_variants = NameVariants::for_name(&name, None);
+    let formal: Vec<&str> = name_variants.iter_with_original().collect();
+
+    let mut variants: Vec<String> = formal.iter().map(|name| name.to_string()).collect();
+
+    for (nickname, names) in NAMES_BY_IRREGULAR_NICK.entries() {
+        if formal.iter().any(|name| names.contains(*name)) {
+            variants.push(nickname.to_string());
+        }
+    }
+
+    for (prefix, names) in NAMES_BY_NICK_PREFIX.entries() {
+        if formal.iter().any(|name| names.contains(*name)) {
+            for ending in &["y", "ie", "ey"] {
+                variants.push(format!("{}{}", prefix, ending));
+            }
+        }
+    }
+
+    for name in &formal {
+        variants.extend(diminutives(name));
+        variants.extend(final_syllables(name));
+    }
+
+    let mut seen = HashSet::with_capacity(variants.len());
+    variants.retain(|variant| seen.insert(variant.clone()));
+    variants.into_iter()
+}
+
+// Those `matches_without_diminutive` accepts which are formed from the first
+// syllable (e.g. "Willy" for "William"), or for names ending in "a" or "o",
+// from the whole name (e.g. "Danielita" for "Daniela")
+fn diminutives(name: &str) -> Vec<String> {
+    let last = match name.char_indices().last() {
+        Some((i, _)) => i,
+        None => return Vec::new(),
+    };
+
+    let syllable = first_syllable(name);
+    let mut diminutives = vec![format!("{}y", syllable), format!("{}ie", syllable)];
+
+    let stem = &name[..last];
+    if name.ends_with('a') {
+        diminutives.push(format!("{}ita", stem));
+        diminutives.push(format!("{}ina", stem));
+    } else if name.ends_with('o') {
+        diminutives.push(format!("{}ito", stem));
+    }
+
+    diminutives
+        .retain(|diminutive| diminutive != name && matches_without_diminutive(diminutive, name));
+    diminutives
+}
+
+// Through the first vowels and up to two following consonants, e.g. "Will"
+// in "William" or "Christ" in "Christopher"
+fn first_syllable(name: &str) -> &str {
+    let bytes = name.as_bytes();
+    let mut i = 0;
+
+    while i < bytes.len() && !is_vowel_at(bytes, i) {
+        i += 1;
+    }
+    while i < bytes.len() && is_vowel_at(bytes, i) {
+        i += 1;
+    }
+    for _ in 0..2 {
+        if i < bytes.len() && !is_vowel_at(bytes, i) {
+            i += 1;
+        }
+    }
+
+    // Not splitting any non-ASCII letter
+    while !name.is_char_boundary(i) {
+        i -= 1;
+    }
+
+    &name[..i]
+}
+
+// Those `is_final_syllables_of` accepts which start a syllable, e.g. "Beth"
+// for "Elizabeth" or "Liam" for "William" (but not "Lliam" or "Pher")
+fn final_syllables(name: &str) -> Vec<String> {
+    let lowercase = name.to_ascii_lowercase();
+    let bytes = lowercase.as_bytes();
+
+    (1..bytes.len())
+        .filter(|&i| {
+            // A consonant followed by a vowel, and preceded by a vowel or
+            // the same consonant, as in "Wil-liam"
+            let starts_syllable = !is_vowel_at(bytes, i)
+                && i + 1 < bytes.len()
+                && is_vowel_at(bytes, i + 1)
+                && (is_vowel_at(bytes, i - 1) || bytes[i - 1] == bytes[i]);
+            starts_syllable || lowercase[i..].starts_with("ann")
+        })
+        .map(|i| capitalize_ascii(&lowercase[i..]))
+        .filter(|syllables| is_final_syllables_of(syllables, name))
+        .collect()
+}
+
+// Treating "y" as a vowel except at the start of a name
+fn is_vowel_at(bytes: &[u8], i: usize) -> bool {
+    match bytes[i].to_ascii_lowercase() {
+        b'a' | b'e' | b'i' | b'o' | b'u' => true,
+        b'y' => i > 0,
+        _ => false,
+    }
+}
+
+fn capitalize_ascii(word: &str) -> String {
+    let mut capitalized = word.to_string();
+    if let Some(first) = capitalized.get_mut(0..1) {
+        first.make_ascii_uppercase();
+    }
+    capitalized
+}
+
+/// Which rule matched two given names, in `matching_variant_kind`
+#[derive(Clone, Copy, Debug, Eq, PartialEq, Hash)]
+pub enum VariantKind {
+    // One name is in our nickname tables for the other (e.g. "Bill" and
+    // "William"), including after matching by one of the other rules
+    Nickname,
+    // E.g. "Dave" and "David"
+    Prefix,
+    // E.g. "Kenny" and "Kenneth"
+    Diminutive,
+    // E.g. "Beth" and "Elizabeth"
+    FinalSyllables,
+    // E.g. "John" and "Juan", with `MatchOptions::allow_cognates`
+    Cognate,
+}
+
+pub fn have_matching_variants(original_a: &str, original_b: &str, options: &MatchOptions) -> bool {
+    matching_variant_kind(original_a, original_b, options).is_some()
+}
+
+pub fn matching_variant_kind(
+    original_a: &str,
+    original_b: &str,
+    options: &MatchOptions,
+) -> Option<VariantKind> {
     let original_a = to_ascii(original_a);
     let original_b = to_ascii(original_b);
 
-    let a_variants = NameVariants::for_name(&*original_a);
-    let b_variants = NameVariants::for_name(&*original_b);
+    if !options.allow_nicknames {
+        return if options.allow_given_name_prefixes && have_prefix_match(&original_a, &original_b) {
+            Some(VariantKind::Prefix)
+        } else if options.allow_cognates && are_cognates(&original_a, &original_b) {
+            Some(VariantKind::Cognate)
+        } else {
+            None
+        };
+    }
+
+    let a_variants = NameVariants::for_name(&*original_a, options.nicknames);
+    let b_variants = NameVariants::for_name(&*original_b, options.nicknames);
 
-    a_variants.iter_with_original().any(|a| {
-        b_variants
+    // The originals always come first, so a match between them is preferred
+    let kind = a_variants
+        .iter_with_original()
+        .enumerate()
+        .filter_map(|(i, a)| {
+            b_variants
+                .iter_with_original()
+                .enumerate()
+                .filter_map(|(j, b)| {
+                    variants_match(a, b, options.allow_given_name_prefixes).map(|kind| {
+                        if i == 0 && j == 0 {
+                            kind
+                        } else {
+                            VariantKind::Nickname
+                        }
+                    })
+                })
+                .next()
+        })
+        .next();
+
+    // Cognates of nicknames count too, as in "Bill" and "Guillaume"
+    if kind.is_none()
+        && options.allow_cognates
+        && a_variants
             .iter_with_original()
-            .any(|b| variants_match(a, b))
-    })
+            .any(|a| b_variants.iter_with_original().any(|b| are_cognates(a, b)))
+    {
+        Some(VariantKind::Cognate)
+    } else {
+        kind
+    }
 }
 
-fn variants_match(a: &str, b: &str) -> bool {
-    have_prefix_match(a, b)
-        || is_final_syllables_of(a, b)
-        || is_final_syllables_of(b, a)
-        || matches_without_diminutive(a, b)
-        || matches_without_diminutive(b, a)
+fn variants_match(a: &str, b: &str, allow_prefixes: bool) -> Option<VariantKind> {
+    if a == b || (allow_prefixes && have_prefix_match(a, b)) {
+        Some(VariantKind::Prefix)
+    } else if is_final_syllables_of(a, b) || is_final_syllables_of(b, a) {
+        Some(VariantKind::FinalSyllables)
+    } else if matches_without_diminutive(a, b) || matches_without_diminutive(b, a) {
+        Some(VariantKind::Diminutive)
+    } else {
+        None
+    }
 }
 
 #[allow(clippy::needless_bool)]
@@ -262,6 +714,10 @@ mod tests {
     use super::*;
     use test::{black_box, Bencher};
 
+    fn have_matching_variants(a: &str, b: &str) -> bool {
+        super::have_matching_variants(a, b, &MatchOptions::default())
+    }
+
     #[test]
     fn nick_and_name() {
         assert!(have_matching_variants("Dave", "David"));
@@ -295,6 +751,193 @@ mod tests {
         assert!(have_matching_variants("Lousie", "Lulu"));
     }
 
+    #[test]
+    fn variant_kinds() {
+        assert_eq!(
+            Some(VariantKind::Nickname),
+            matching_variant_kind("Bill", "William", &MatchOptions::default())
+        );
+        assert_eq!(
+            Some(VariantKind::Prefix),
+            matching_variant_kind("Dan", "Daniel", &MatchOptions::default())
+        );
+        assert_eq!(
+            Some(VariantKind::Diminutive),
+            matching_variant_kind("Kenny", "Kenneth", &MatchOptions::default())
+        );
+        assert_eq!(
+            Some(VariantKind::FinalSyllables),
+            matching_variant_kind("Beth", "Elizabeth", &MatchOptions::default())
+        );
+        assert_eq!(
+            None,
+            matching_variant_kind("Jane", "John", &MatchOptions::default())
+        );
+    }
+
+    #[test]
+    fn strict_variants() {
+        let no_prefixes = MatchOptions {
+            allow_given_name_prefixes: false,
+            ..Default::default()
+        };
+        assert_eq!(None, matching_variant_kind("Dan", "Daniel", &no_prefixes));
+        assert_eq!(None, matching_variant_kind("Will", "Bill", &no_prefixes));
+        assert_eq!(
+            Some(VariantKind::Nickname),
+            matching_variant_kind("Bill", "William", &no_prefixes)
+        );
+
+        let no_nicknames = MatchOptions {
+            allow_nicknames: false,
+            ..Default::default()
+        };
+        assert_eq!(
+            None,
+            matching_variant_kind("Bill", "William", &no_nicknames)
+        );
+        assert_eq!(
+            None,
+            matching_variant_kind("Kenny", "Kenneth", &no_nicknames)
+        );
+        assert_eq!(
+            Some(VariantKind::Prefix),
+            matching_variant_kind("Dan", "Daniel", &no_nicknames)
+        );
+    }
+
+    #[test]
+    fn custom_variants() {
+        let mut db = NicknameDb::new();
+        db.insert("TIKO", "teodoro");
+        db.insert("Ğüzel", "Guzelia");
+        let options = MatchOptions {
+            nicknames: Some(&db),
+            ..Default::default()
+        };
+        assert!(super::have_matching_variants("Tiko", "Teodoro", &options));
+        assert!(super::have_matching_variants("Teodoro", "Tiko", &options));
+        assert!(super::have_matching_variants("Guzel", "Guzelia", &options));
+        assert!(super::have_matching_variants("Bill", "William", &options));
+        assert!(!have_matching_variants("Tiko", "Teodoro"));
+
+        let mut db = NicknameDb::without_builtin();
+        db.insert("Tiko", "Teodoro");
+        let options = MatchOptions {
+            nicknames: Some(&db),
+            ..Default::default()
+        };
+        assert!(super::have_matching_variants("Tiko", "Teodoro", &options));
+        assert!(super::have_matching_variants("Dan", "Daniel", &options));
+        assert!(!super::have_matching_variants("Bill", "William", &options));
+        assert!(!super::have_matching_variants("Abby", "Abigail", &options));
+    }
+
+    #[test]
+    fn load_nicknames() {
+        let mut db = NicknameDb::new();
+        assert_eq!(Ok(()), db.load("Tiko, Teodoro\n\n# comment\nBeto\tRoberto"));
+        assert_eq!(Some(&["Teodoro".to_string()][..]), db.get("Tiko"));
+        assert_eq!(Some(&["Roberto".to_string()][..]), db.get("Beto"));
+
+        let mut db = NicknameDb::new();
+        assert_eq!(
+            Err(NicknameDbError { line: 2 }),
+            db.load("Tiko,Teodoro\nBeto,Roberto,Bob")
+        );
+        assert_eq!(NicknameDb::new(), db);
+        assert_eq!(Err(NicknameDbError { line: 1 }), db.load("Tiko,"));
+    }
+
+    #[test]
+    fn all_variants_match() {
+        for name in &[
+            "William",
+            "Bill",
+            "Bob",
+            "Elizabeth",
+            "Daniela",
+            "Marianne",
+            "Ivo",
+            "Al",
+        ] {
+            let variants: Vec<String> = variants_of(name).collect();
+            assert_eq!(*name, variants[0]);
+            for variant in &variants {
+                assert!(
+                    have_matching_variants(name, variant),
+                    "{} {}",
+                    name,
+                    variant
+                );
+            }
+        }
+    }
+
+    #[test]
+    fn listed_variants() {
+        let variants: Vec<String> = variants_of("elizabeth").collect();
+        assert_eq!("Elizabeth", variants[0]);
+        assert!(variants.contains(&"Beth".to_string()));
+        assert!(variants.contains(&"Bess".to_string()));
+        assert!(variants.contains(&"Bessie".to_string()));
+
+        let variants: Vec<String> = variants_of("Daniela").collect();
+        assert!(variants.contains(&"Danielita".to_string()));
+
+        let variants: Vec<String> = variants_of("Marianne").collect();
+        assert!(variants.contains(&"Anne".to_string()));
+        assert!(!variants.contains(&"Nne".to_string()));
+    }
+
+    #[test]
+    fn unusual_variants() {
+        assert_eq!(vec![""], variants_of("").collect::<Vec<_>>());
+
+        let variants: Vec<String> = variants_of("José").collect();
+        assert_eq!("Jose", variants[0]);
+        assert!(variants.contains(&"Josie".to_string()));
+
+        assert_eq!(vec!["Josy", "Josie"], diminutives("José"));
+    }
+
+    #[test]
+    fn cognate_variants() {
+        let cognates = MatchOptions {
+            allow_cognates: true,
+            ..Default::default()
+        };
+        assert_eq!(
+            Some(VariantKind::Cognate),
+            matching_variant_kind("Giovanni", "John", &cognates)
+        );
+        assert_eq!(
+            Some(VariantKind::Cognate),
+            matching_variant_kind("Bill", "Guillaume", &cognates)
+        );
+        assert_eq!(
+            Some(VariantKind::Nickname),
+            matching_variant_kind("Bill", "William", &cognates)
+        );
+        assert_eq!(
+            None,
+            matching_variant_kind("Giovanni", "John", &MatchOptions::default())
+        );
+
+        let only_cognates = MatchOptions {
+            allow_nicknames: false,
+            ..cognates
+        };
+        assert_eq!(
+            Some(VariantKind::Cognate),
+            matching_variant_kind("Juan", "Johann", &only_cognates)
+        );
+        assert_eq!(
+            None,
+            matching_variant_kind("Bill", "Guillaume", &only_cognates)
+        );
+    }
+
     #[test]
     fn nonmatching_nicks() {
         assert!(!have_matching_variants("Xina", "Xander"));
@@ -351,6 +994,38 @@ mod tests {
         assert_eq!("Ro'bert R'oberts", strip_nickname("Ro'bert R'oberts"));
     }
 
+    #[test]
+    fn namecased_nickname() {
+        assert_eq!("Bill", namecase_nickname("Bill"));
+        assert_eq!("Bill", namecase_nickname("BILL"));
+        assert_eq!("Don Juan", namecase_nickname("don  juan"));
+        assert_eq!("McBill", namecase_nickname("McBill"));
+    }
+
+    #[test]
+    fn capture_nickname() {
+        assert_eq!(
+            ("William Gates".into(), Some("Bill")),
+            strip_and_capture_nickname("William (Bill) Gates")
+        );
+        assert_eq!(
+            ("Juan Garcia".into(), Some("Don Juan")),
+            strip_and_capture_nickname("Juan 'Don Juan' Garcia")
+        );
+        assert_eq!(
+            ("Alexander Petrov".into(), Some("Sasha")),
+            strip_and_capture_nickname("Alexander «Sasha» Petrov")
+        );
+        assert_eq!(
+            ("Robert Roberts".into(), None),
+            strip_and_capture_nickname("Robert ( ) Roberts")
+        );
+        assert_eq!(
+            ("Robert".into(), None),
+            strip_and_capture_nickname("Robert (Mr. Bob")
+        );
+    }
+
     #[bench]
     fn strip_nick_no_nick(b: &mut Bencher) {
         b.iter(|| {
@@ -1,41 +1,206 @@
-use super::namepart::{Location, NamePart};
+use super::namepart::{Category, Location, NamePart};
 use super::suffix;
 use super::surname;
 use super::title;
-use super::utils::is_mixed_case;
+use super::utils::{is_cjk_text, is_mixed_case, split_first_hangul_syllable};
 use smallvec::SmallVec;
+use std::borrow::Cow;
+use std::error::Error;
+use std::fmt;
 
 #[derive(Debug)]
 struct ParseOp<'a> {
     words: SmallVec<[NamePart<'a>; 7]>,
     surname_index: usize,
     generation_from_suffix: Option<u8>,
+    suffix_word: Option<&'a str>,
     possible_false_prefix: Option<NamePart<'a>>,
     possible_false_postfix: Option<NamePart<'a>>,
+    prefix_titles: SmallVec<[NamePart<'a>; 2]>,
+    postfix_titles: SmallVec<[NamePart<'a>; 2]>,
     use_capitalization: bool,
+    surname_first: bool,
+    iberian_surnames: bool,
+    arabic_patronymics: bool,
+}
+
+/// The order in which given name and surname appear, when not made explicit
+/// by a comma (as in "Doe, Jane", which is always understood as surname-first).
+#[derive(Clone, Copy, Debug, Eq, PartialEq, Hash)]
+pub enum NameOrder {
+    /// Given name first, as in "Jane Doe" (the default).
+    GivenFirst,
+    /// Surname first, as in "Kim Il-sung" or "Orbán Viktor".
+    SurnameFirst,
+    /// Surname first for names written entirely in Han, Hangul or kana
+    /// characters, otherwise given name first.
+    Auto,
+}
+
+/// Options controlling how `Name::parse_with_options` interprets its input.
+#[derive(Clone, Copy, Debug, Eq, PartialEq, Hash)]
+pub struct ParseOptions {
+    pub name_order: NameOrder,
+    /// Expect Spanish or Portuguese style paternal and maternal surnames,
+    /// so that "Juan Pérez González" has the surname "Pérez González" rather
+    /// than the middle name "Pérez".
+    ///
+    /// Names parsed with this option compare and hash on the paternal
+    /// surname, so they can't be usefully matched against names parsed
+    /// without it: use the same options for every name in a `NameIndex` or
+    /// `cluster`.
+    ///
+    /// ```
+    /// use human_name::{Name, ParseOptions};
+    ///
+    /// let options = ParseOptions {
+    ///     iberian_surnames: true,
+    ///     ..Default::default()
+    /// };
+    /// let iberian = Name::parse_with_options("Juan Pérez González", &options).unwrap();
+    /// let default = Name::parse("Juan Pérez González").unwrap();
+    /// assert!(!iberian.consistent_with(&default));
+    /// assert_ne!(iberian.hash, default.hash);
+    /// ```
+    pub iberian_surnames: bool,
+    /// Expect chains of Arabic patronymics before the surname, so that
+    /// "Mohammed bin Salman Al Saud" has the surname "Al Saud" and the
+    /// patronymic "Bin Salman", rather than the surname "bin Salman Al Saud".
+    ///
+    /// As with `iberian_surnames`, use the same options for every name you
+    /// compare, since the option changes the surname.
+    pub arabic_patronymics: bool,
+}
+
+impl Default for ParseOptions {
+    fn default() -> ParseOptions {
+        ParseOptions {
+            name_order: NameOrder::GivenFirst,
+            iberian_surnames: false,
+            arabic_patronymics: false,
+        }
+    }
+}
+
+/// Prefix and postfix titles found while parsing, in input order.
+#[derive(Debug, Default)]
+pub struct Titles<'a> {
+    pub prefix: SmallVec<[Cow<'a, str>; 2]>,
+    pub postfix: SmallVec<[Cow<'a, str>; 2]>,
+}
+
+/// The words of the input which titles and any generational suffix were
+/// parsed from, as written, so they can be located in it.
+#[derive(Debug, Default)]
+pub struct TitleWords<'a> {
+    pub prefix: SmallVec<[&'a str; 2]>,
+    pub postfix: SmallVec<[&'a str; 2]>,
+    pub suffix: Option<&'a str>,
 }
 
 pub const MAX_WORDS: usize = u8::max_value() as usize;
 
-pub fn parse(name: &str) -> Option<(SmallVec<[NamePart; 7]>, usize, Option<u8>)> {
+/// Why a string could not be parsed as a name.
+///
+/// Returned by `Name::try_parse`.
+#[derive(Clone, Copy, Debug, Eq, PartialEq, Hash)]
+pub enum ParseError {
+    /// The input was `MAX_NAME_LEN` bytes or longer.
+    TooLong,
+    /// Fewer than two words were left after stripping titles, suffixes,
+    /// nicknames and junk (e.g. "Smith" or "Dr. Smith").
+    TooFewWords,
+    /// More than `MAX_SEGMENTS` words were left.
+    TooManyWords,
+    /// A word was left which looks like neither a name nor initials (e.g. a
+    /// word made up mostly of punctuation).
+    UnexpectedWord,
+    /// None of the words in the apparent surname looked like a name.
+    NoSurname,
+    /// A part passed to `Name::from_components` had no words.
+    EmptyComponent,
+}
+
+impl fmt::Display for ParseError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let description = match *self {
+            ParseError::TooLong => "input too long",
+            ParseError::TooFewWords => "too few words",
+            ParseError::TooManyWords => "too many words",
+            ParseError::UnexpectedWord => "word is neither a name nor initials",
+            ParseError::NoSurname => "no namelike surname",
+            ParseError::EmptyComponent => "empty name component",
+        };
+        f.write_str(description)
+    }
+}
+
+impl Error for ParseError {}
+
+pub fn parse(
+    name: &str,
+) -> Result<(SmallVec<[NamePart; 7]>, usize, Option<u8>, Titles), ParseError> {
+    let (words, surname_index, generation_from_suffix, titles, _) =
+        parse_with_options(name, &ParseOptions::default())?;
+    Ok((words, surname_index, generation_from_suffix, titles))
+}
+
+#[allow(clippy::type_complexity)]
+pub fn parse_with_options<'a>(
+    name: &'a str,
+    options: &ParseOptions,
+) -> Result<
+    (
+        SmallVec<[NamePart<'a>; 7]>,
+        usize,
+        Option<u8>,
+        Titles<'a>,
+        TitleWords<'a>,
+    ),
+    ParseError,
+> {
     let mut op = ParseOp {
         words: SmallVec::new(),
         surname_index: 0,
         generation_from_suffix: None,
+        suffix_word: None,
         possible_false_prefix: None,
         possible_false_postfix: None,
+        prefix_titles: SmallVec::new(),
+        postfix_titles: SmallVec::new(),
         use_capitalization: is_mixed_case(name),
+        surname_first: match options.name_order {
+            NameOrder::GivenFirst => false,
+            NameOrder::SurnameFirst => true,
+            NameOrder::Auto => is_cjk_text(name),
+        },
+        iberian_surnames: options.iberian_surnames,
+        arabic_patronymics: options.arabic_patronymics,
     };
 
-    if op.run(name) {
-        Some((op.words, op.surname_index, op.generation_from_suffix))
-    } else {
-        None
-    }
+    op.run(name)?;
+
+    let titles = Titles {
+        prefix: op.prefix_titles.iter().map(|w| op.title_text(w)).collect(),
+        postfix: op.postfix_titles.iter().map(|w| op.title_text(w)).collect(),
+    };
+    let title_words = TitleWords {
+        prefix: op.prefix_titles.iter().map(|w| w.word).collect(),
+        postfix: op.postfix_titles.iter().map(|w| w.word).collect(),
+        suffix: op.suffix_word,
+    };
+
+    Ok((
+        op.words,
+        op.surname_index,
+        op.generation_from_suffix,
+        titles,
+        title_words,
+    ))
 }
 
 impl<'a> ParseOp<'a> {
-    fn run(&mut self, name: &'a str) -> bool {
+    fn run(&mut self, name: &'a str) -> Result<(), ParseError> {
         // Separate comma-separated titles and suffixes, then flip remaining words
         // around remaining comma, if any
         let mut parts = name.split(',').peekable();
@@ -71,8 +236,12 @@ impl<'a> ParseOp<'a> {
         if !self.valid() {
             if let Some(ref postfix) = self.possible_false_postfix {
                 self.words.push(postfix.clone());
+                self.postfix_titles
+                    .retain(|w| w.word.as_ptr() != postfix.word.as_ptr());
             } else if let Some(ref prefix) = self.possible_false_prefix {
                 self.words.insert(0, prefix.clone());
+                self.prefix_titles
+                    .retain(|w| w.word.as_ptr() != prefix.word.as_ptr());
             }
         }
 
@@ -100,23 +269,36 @@ impl<'a> ParseOp<'a> {
         // but we never found a plausible given name or initial afterwards,
         // as well as the reset just above
         if self.surname_index == 0 && self.words.len() > 1 {
-            self.surname_index = surname::find_surname_index(&self.words[1..]) + 1;
+            self.surname_index = self.find_surname_index(&self.words[1..]) + 1;
         }
 
         // Check the plausibility of what we've found
-        self.valid()
+        self.validate()
     }
 
     fn valid(&self) -> bool {
-        self.words.len() >= 2
-            && self.words.len() <= MAX_WORDS
-            && self
-                .words
-                .iter()
-                .all(|w| w.is_namelike() || w.is_initials())
-            && self.words[self.surname_index..]
-                .iter()
-                .any(|w| w.is_namelike())
+        self.validate().is_ok()
+    }
+
+    fn validate(&self) -> Result<(), ParseError> {
+        if self.words.len() < 2 {
+            Err(ParseError::TooFewWords)
+        } else if self.words.len() > MAX_WORDS {
+            Err(ParseError::TooManyWords)
+        } else if !self
+            .words
+            .iter()
+            .all(|w| w.is_namelike() || w.is_initials())
+        {
+            Err(ParseError::UnexpectedWord)
+        } else if !self.words[self.surname_index..]
+            .iter()
+            .any(|w| w.is_namelike())
+        {
+            Err(ParseError::NoSurname)
+        } else {
+            Ok(())
+        }
     }
 
     fn handle_no_comma(&mut self, name: &'a str) {
@@ -128,15 +310,26 @@ impl<'a> ParseOp<'a> {
             "Invalid state for handle_no_comma!"
         );
 
-        self.words.extend(
-            NamePart::all_from_text(name, self.use_capitalization, Location::Start)
-                .skip_while(|word| !word.is_namelike() && !word.is_initials()),
-        );
+        let mut found_name = false;
+        for word in NamePart::all_from_text(name, self.use_capitalization, Location::Start) {
+            if found_name || word.is_namelike() || word.is_initials() {
+                found_name = true;
+                self.words.push(word);
+            } else if word.category == Category::Abbreviation {
+                // Leading abbreviations are skipped, but might still be titles
+                // (e.g. "Mr. & Mrs. John Doe")
+                self.prefix_titles.push(word);
+            }
+        }
 
         if self.words.is_empty() {
             return;
         }
 
+        if self.surname_first && self.words.len() == 1 {
+            self.split_unspaced_surname();
+        }
+
         // Check for title as prefix (e.g. "Dr. John Smith" or "Right Hon.
         // John Smith")
         let prefix_title_len = if self.words.len() > 2 {
@@ -155,7 +348,35 @@ impl<'a> ParseOp<'a> {
             };
         self.strip_postfix(first_postfix_index);
 
-        self.surname_index = surname::find_surname_index(&self.words[1..]) + 1;
+        if self.surname_first {
+            // Put the given name(s) first, as in every other format
+            let surname_len = surname::find_leading_surname_len(&self.words);
+            self.words.rotate_left(surname_len);
+            self.surname_index = self.words.len() - surname_len;
+        } else {
+            self.surname_index = self.find_surname_index(&self.words[1..]) + 1;
+        }
+    }
+
+    fn find_surname_index(&self, words: &[NamePart]) -> usize {
+        if self.iberian_surnames {
+            surname::find_iberian_surname_index(words, self.arabic_patronymics)
+        } else {
+            surname::find_surname_index(words, self.arabic_patronymics)
+        }
+    }
+
+    // Handles the common case of a Korean name written without a space
+    fn split_unspaced_surname(&mut self) {
+        let word = self.words[0].word;
+        if let Some((surname, given_name)) = split_first_hangul_syllable(word) {
+            self.words[0] = NamePart::from_word(surname, self.use_capitalization, Location::Start);
+            self.words.push(NamePart::from_word(
+                given_name,
+                self.use_capitalization,
+                Location::End,
+            ));
+        }
     }
 
     // Called only until any words are found
@@ -189,14 +410,14 @@ impl<'a> ParseOp<'a> {
         if prefix_title_len > 0 {
             // Finding a prefix title means the next word is a first name or
             // initial (we don't support "Dr. Smith, John")
-            self.surname_index = surname::find_surname_index(&self.words[1..]) + 1;
+            self.surname_index = self.find_surname_index(&self.words[1..]) + 1;
         } else {
             // Have to guess whether this is just the surname (as in "Smith, John")
             // or the full name (as in "John Smith")
             //
             // Note we might be wrong, and have to go back, if we think the given
             // name is coming after a comma, but it never does
-            self.surname_index = surname::find_surname_index(&self.words);
+            self.surname_index = self.find_surname_index(&self.words);
         }
     }
 
@@ -248,38 +469,37 @@ impl<'a> ParseOp<'a> {
             "Invalid state for handle_after_surname!"
         );
 
-        if self.possible_false_postfix.is_some() && self.generation_from_suffix.is_some() {
-            return;
-        }
-
-        let mut postfix_words =
-            NamePart::all_from_text(part, self.use_capitalization, Location::End);
-        while self.possible_false_postfix.is_none() || self.generation_from_suffix.is_none() {
-            if let Some(word) = postfix_words.next() {
-                self.found_suffix_or_postfix(word, false);
-            } else {
-                break;
-            }
+        for word in NamePart::all_from_text(part, self.use_capitalization, Location::End) {
+            self.found_suffix_or_postfix(word, false);
         }
     }
 
     fn strip_prefix(&mut self, len: usize) {
+        let prior_len = self.prefix_titles.len();
         for i in (0..len).rev() {
             let word = self.words.remove(i);
             self.found_prefix(word);
         }
+        self.prefix_titles[prior_len..].reverse();
     }
 
     fn strip_unsaved_prefix(&mut self, words: &mut SmallVec<[NamePart<'a>; 5]>, len: usize) {
+        let prior_len = self.prefix_titles.len();
         for i in (0..len).rev() {
             self.found_prefix(words.remove(i));
         }
+        self.prefix_titles[prior_len..].reverse();
     }
 
+    // Called in reverse order by the `strip_*` methods
     fn found_prefix(&mut self, prefix: NamePart<'a>) {
-        // We drop prefixes, but keep the last word that's namelike,
-        // just in case we make a mistake and it turns out by process of
-        // elimination that this must actually be a given name
+        if prefix.category != Category::Other {
+            self.prefix_titles.push(prefix.clone());
+        }
+
+        // Keep the last word that's namelike separately, just in case we make
+        // a mistake and it turns out by process of elimination that this must
+        // actually be a given name
         if self.possible_false_prefix.is_none() && (prefix.is_namelike() || prefix.is_initials()) {
             self.possible_false_prefix = Some(prefix);
         }
@@ -287,33 +507,67 @@ impl<'a> ParseOp<'a> {
 
     fn strip_postfix(&mut self, index: usize) {
         if index < self.words.len() {
-            let postfix = self.words.swap_remove(index);
+            let postfix = self.words.remove(index);
             self.found_suffix_or_postfix(postfix, false);
-            self.words.truncate(index);
+
+            // Words after the first postfix are kept as titles, but aren't
+            // considered as generational suffixes or possible surnames
+            while self.words.len() > index {
+                let postfix = self.words.remove(index);
+                self.found_postfix_title(postfix);
+            }
         }
     }
 
     fn strip_unsaved_postfix(&mut self, words: &mut SmallVec<[NamePart<'a>; 5]>, index: usize) {
         if index < words.len() {
-            let postfix = words.swap_remove(index);
+            let postfix = words.remove(index);
             self.found_suffix_or_postfix(postfix, false);
-            words.truncate(index);
+
+            while words.len() > index {
+                let postfix = words.remove(index);
+                self.found_postfix_title(postfix);
+            }
         }
     }
 
     fn found_suffix_or_postfix(&mut self, postfix: NamePart<'a>, expect_initials: bool) {
-        if self.generation_from_suffix.is_none() {
-            self.generation_from_suffix = suffix::generation_from_suffix(&postfix, expect_initials);
+        let generation = suffix::generation_from_suffix(&postfix, expect_initials);
+        if generation.is_none() {
+            if postfix.category != Category::Other && !title::is_list_postfix(&postfix) {
+                self.postfix_titles.push(postfix.clone());
+            }
+        } else if self.generation_from_suffix.is_none() {
+            self.generation_from_suffix = generation;
+            self.suffix_word = Some(postfix.word);
         }
 
-        // We throw away most postfix titles, but keep the first one that's namelike,
-        // just in case we make a mistake and it turns out by process of elimination
-        // that this must actually be a surname
+        // Keep the first postfix that's namelike separately, just in case we
+        // make a mistake and it turns out by process of elimination that this
+        // must actually be a surname
         if self.possible_false_postfix.is_none() && (postfix.is_namelike() || postfix.is_initials())
         {
             self.possible_false_postfix = Some(postfix);
         }
     }
+
+    fn found_postfix_title(&mut self, postfix: NamePart<'a>) {
+        if postfix.category != Category::Other
+            && !title::is_list_postfix(&postfix)
+            && suffix::generation_from_suffix(&postfix, false).is_none()
+        {
+            self.postfix_titles.push(postfix);
+        }
+    }
+
+    // Titles are displayed as given, unless we couldn't trust the input's
+    // capitalization, in which case we namecase them like any other word
+    fn title_text(&self, word: &NamePart<'a>) -> Cow<'a, str> {
+        match word.category {
+            Category::Name(ref namecased) if !self.use_capitalization => namecased.clone(),
+            _ => Cow::Borrowed(word.word),
+        }
+    }
 }
 
 #[cfg(test)]
@@ -323,7 +577,7 @@ mod tests {
 
     #[test]
     fn first_last() {
-        let (parts, surname_index, generation) = parse("John Doe").unwrap();
+        let (parts, surname_index, generation, _) = parse("John Doe").unwrap();
         assert_eq!("John", parts[0].word);
         assert_eq!("Doe", parts[1].word);
         assert_eq!(1, surname_index);
@@ -332,7 +586,7 @@ mod tests {
 
     #[test]
     fn initial_last() {
-        let (parts, surname_index, generation) = parse("J. Doe").unwrap();
+        let (parts, surname_index, generation, _) = parse("J. Doe").unwrap();
         assert_eq!("J.", parts[0].word);
         assert_eq!("Doe", parts[1].word);
         assert_eq!(1, surname_index);
@@ -341,7 +595,7 @@ mod tests {
 
     #[test]
     fn last_first() {
-        let (parts, surname_index, generation) = parse("Doe, John").unwrap();
+        let (parts, surname_index, generation, _) = parse("Doe, John").unwrap();
         assert_eq!("John", parts[0].word);
         assert_eq!("Doe", parts[1].word);
         assert_eq!(1, surname_index);
@@ -350,7 +604,7 @@ mod tests {
 
     #[test]
     fn last_initial() {
-        let (parts, surname_index, generation) = parse("Doe, J.").unwrap();
+        let (parts, surname_index, generation, _) = parse("Doe, J.").unwrap();
         assert_eq!("J.", parts[0].word);
         assert_eq!("Doe", parts[1].word);
         assert_eq!(1, surname_index);
@@ -359,7 +613,7 @@ mod tests {
 
     #[test]
     fn suffix() {
-        let (parts, surname_index, generation) = parse("John Doe III").unwrap();
+        let (parts, surname_index, generation, _) = parse("John Doe III").unwrap();
         assert_eq!("John", parts[0].word);
         assert_eq!("Doe", parts[1].word);
         assert_eq!(1, surname_index);
@@ -368,7 +622,7 @@ mod tests {
 
     #[test]
     fn suffix_comma() {
-        let (parts, surname_index, generation) = parse("Doe, John III").unwrap();
+        let (parts, surname_index, generation, _) = parse("Doe, John III").unwrap();
         assert_eq!("John", parts[0].word);
         assert_eq!("Doe", parts[1].word);
         assert_eq!(1, surname_index);
@@ -377,41 +631,125 @@ mod tests {
 
     #[test]
     fn intermediate_suffix() {
-        let (parts, surname_index, generation) = parse("Doe, II, John").unwrap();
+        let (parts, surname_index, generation, _) = parse("Doe, II, John").unwrap();
         assert_eq!("John", parts[0].word);
         assert_eq!("Doe", parts[1].word);
         assert_eq!(1, surname_index);
         assert_eq!(Some(2), generation);
 
-        let (parts, surname_index, generation) = parse("Griffey, Jr., Ken").unwrap();
+        let (parts, surname_index, generation, _) = parse("Griffey, Jr., Ken").unwrap();
         assert_eq!("Ken", parts[0].word);
         assert_eq!("Griffey", parts[1].word);
         assert_eq!(1, surname_index);
         assert_eq!(Some(2), generation);
     }
 
+    #[test]
+    fn surname_first() {
+        let options = ParseOptions {
+            name_order: NameOrder::SurnameFirst,
+            ..Default::default()
+        };
+
+        let (parts, surname_index, _, _, _) = parse_with_options("Kim Il-sung", &options).unwrap();
+        assert_eq!("Il-sung", parts[0].word);
+        assert_eq!("Kim", parts[1].word);
+        assert_eq!(1, surname_index);
+
+        let (parts, surname_index, _, titles, _) =
+            parse_with_options("Dr. Orbán Viktor Jr.", &options).unwrap();
+        assert_eq!("Viktor", parts[0].word);
+        assert_eq!("Orbán", parts[1].word);
+        assert_eq!(1, surname_index);
+        assert_eq!(vec!["Dr."], titles.prefix.to_vec());
+
+        // Commas are still respected
+        let (parts, surname_index, _, _, _) = parse_with_options("Il-sung, Kim", &options).unwrap();
+        assert_eq!("Kim", parts[0].word);
+        assert_eq!("Il-sung", parts[1].word);
+        assert_eq!(1, surname_index);
+
+        let (parts, surname_index, _, _, _) = parse_with_options("이용희", &options).unwrap();
+        assert_eq!("용희", parts[0].word);
+        assert_eq!("이", parts[1].word);
+        assert_eq!(1, surname_index);
+    }
+
+    #[test]
+    fn auto_order() {
+        let options = ParseOptions {
+            name_order: NameOrder::Auto,
+            ..Default::default()
+        };
+
+        let (parts, surname_index, _, _, _) = parse_with_options("毛泽东", &options).unwrap();
+        assert_eq!("泽", parts[0].word);
+        assert_eq!("东", parts[1].word);
+        assert_eq!("毛", parts[2].word);
+        assert_eq!(2, surname_index);
+
+        let (parts, surname_index, _, _, _) = parse_with_options("Kim Il-sung", &options).unwrap();
+        assert_eq!("Kim", parts[0].word);
+        assert_eq!(1, surname_index);
+    }
+
+    #[test]
+    fn titles() {
+        let (parts, _, generation, titles) = parse("Dr. John Doe Jr., MD, PhD").unwrap();
+        assert_eq!(2, parts.len());
+        assert_eq!(Some(2), generation);
+        assert_eq!(vec!["Dr."], titles.prefix.to_vec());
+        assert_eq!(vec!["MD", "PhD"], titles.postfix.to_vec());
+
+        let (_, _, _, titles) = parse("Right Hon. Jane Doe Esq.").unwrap();
+        assert_eq!(vec!["Right", "Hon."], titles.prefix.to_vec());
+        assert_eq!(vec!["Esq."], titles.postfix.to_vec());
+
+        let (_, _, _, titles) = parse("ADMIRAL JOHN DOE").unwrap();
+        assert_eq!(vec!["Admiral"], titles.prefix.to_vec());
+        assert!(titles.postfix.is_empty());
+
+        let (_, _, _, titles) = parse("Mr. & Mrs. John Doe").unwrap();
+        assert_eq!(vec!["Mr.", "Mrs."], titles.prefix.to_vec());
+
+        let (_, _, _, titles) = parse("Jane Doe, et al.").unwrap();
+        assert!(titles.postfix.is_empty());
+    }
+
+    #[test]
+    fn errors() {
+        assert_eq!(ParseError::TooFewWords, parse("Smith").unwrap_err());
+        assert_eq!(ParseError::TooFewWords, parse("Dr. Smith").unwrap_err());
+        assert_eq!(ParseError::TooFewWords, parse("").unwrap_err());
+        assert_eq!(ParseError::TooFewWords, parse("X.Y.Z.").unwrap_err());
+        assert_eq!(
+            ParseError::UnexpectedWord,
+            parse("Doe, Jo%%%hn").unwrap_err()
+        );
+    }
+
     #[bench]
     fn parse_simple(b: &mut Bencher) {
-        b.iter(|| black_box(parse("John Doe").is_some()))
+        b.iter(|| black_box(parse("John Doe").is_ok()))
     }
 
     #[bench]
     fn parse_nonascii(b: &mut Bencher) {
-        b.iter(|| black_box(parse("이용희").is_some()))
+        b.iter(|| black_box(parse("이용희").is_ok()))
     }
 
     #[bench]
     fn parse_comma(b: &mut Bencher) {
-        b.iter(|| black_box(parse("Doe, John").is_some()))
+        b.iter(|| black_box(parse("Doe, John").is_ok()))
     }
 
     #[bench]
     fn parse_all_caps(b: &mut Bencher) {
-        b.iter(|| black_box(parse("JOHN DOE").is_some()))
+        b.iter(|| black_box(parse("JOHN DOE").is_ok()))
     }
 
     #[bench]
     fn parse_complex(b: &mut Bencher) {
-        b.iter(|| black_box(parse("James S. Brown MD, FRCS, FDSRCS").is_some()))
+        b.iter(|| black_box(parse("James S. Brown MD, FRCS, FDSRCS").is_ok()))
     }
 }
@@ -0,0 +1,186 @@
+use super::namepart::NamePart;
+use super::utils::{lowercase_if_alpha, transliterate};
+use unicode_normalization::UnicodeNormalization;
+
+// Compared against the transliterated, lowercased word, so Cyrillic forms
+// (e.g. "Иванович") and unaccented Icelandic forms are covered too
+static SLAVIC_PATRONYMIC_SUFFIXES: [&'static str; 7] = [
+    "ovich", "evich", "ovitch", "evitch", "ovna", "evna", "ichna",
+];
+
+static ICELANDIC_DAUGHTER_SUFFIXES: [&'static str; 2] = ["dottir", "dottur"];
+
+// Plenty of other surnames end in "sson" (e.g. "Besson" or "Masson"), so a
+// "son" patronymic is only recognized when what precedes it is the genitive
+// of a common Icelandic given name
+static ICELANDIC_SON_SUFFIX: &'static str = "son";
+static ICELANDIC_GENITIVE_GIVEN_NAMES: [&'static str; 32] = [
+    "andres",
+    "arnars",
+    "asgeirs",
+    "baldurs",
+    "benedikts",
+    "birgis",
+    "bjarkis",
+    "egils",
+    "eiriks",
+    "fridriks",
+    "geirs",
+    "gisla",
+    "gudjons",
+    "hakonar",
+    "halldors",
+    "hallgrims",
+    "hauks",
+    "hermanns",
+    "johanns",
+    "kjartans",
+    "kristjans",
+    "omars",
+    "peturs",
+    "runars",
+    "sigurds",
+    "sigurjons",
+    "steins",
+    "sveins",
+    "thorgeirs",
+    "thorsteins",
+    "thorvalds",
+    "valdimars",
+];
+
+// Followed by "son", these are also common Swedish, Danish or Norwegian
+// surnames (e.g. "Karlsson" or "Magnusson"), so are only recognized when
+// spelled with a letter peculiar to Icelandic, as in "Magnússon"
+static SCANDINAVIAN_GENITIVE_GIVEN_NAMES: [&'static str; 20] = [
+    "bjorns", "daniels", "davids", "einars", "gudmunds", "gunnars", "haralds", "hjalmars",
+    "ingvars", "ivars", "jakobs", "jons", "karls", "magnus", "olafs", "oskars", "pals", "ragnars",
+    "stefans", "thors",
+];
+
+// The acute accent (as a combining mark, since we match against NFKD text),
+// thorn and eth
+static ICELANDIC_MARKS: [char; 5] = ['\u{301}', 'þ', 'Þ', 'ð', 'Ð'];
+
+// "bin Salman", "ibn Sina", "bint Abdullah", etc
+static PATRONYMIC_PARTICLES: [&'static str; 4] = ["bin", "ibn", "bint", "binti"];
+
+// Require at least this many characters before the suffix, to avoid
+// mistaking short names like "Sson" for patronymics
+const MIN_STEM_LEN: usize = 2;
+
+fn transliterated_lowercase(word: &str) -> String {
+    word.chars()
+        .flat_map(transliterate)
+        .filter_map(lowercase_if_alpha)
+        .collect()
+}
+
+fn has_suffix(word: &str, suffixes: &[&str]) -> bool {
+    let lower = transliterated_lowercase(word);
+    suffixes
+        .iter()
+        .any(|suffix| lower.len() >= suffix.len() + MIN_STEM_LEN && lower.ends_with(suffix))
+}
+
+pub fn is_patronymic(word: &str) -> bool {
+    has_suffix(word, &SLAVIC_PATRONYMIC_SUFFIXES) || is_icelandic_patronymic(word)
+}
+
+// Icelanders don't generally have surnames, so a trailing Icelandic
+// patronymic does double duty as the surname
+pub fn is_icelandic_patronymic(word: &str) -> bool {
+    if has_suffix(word, &ICELANDIC_DAUGHTER_SUFFIXES) {
+        return true;
+    }
+
+    let lower = transliterated_lowercase(word);
+    if !lower.ends_with(ICELANDIC_SON_SUFFIX) {
+        return false;
+    }
+
+    let stem = &lower[..lower.len() - ICELANDIC_SON_SUFFIX.len()];
+    ICELANDIC_GENITIVE_GIVEN_NAMES.contains(&stem)
+        || (SCANDINAVIAN_GENITIVE_GIVEN_NAMES.contains(&stem)
+            && word.nfkd().any(|c| ICELANDIC_MARKS.contains(&c)))
+}
+
+pub fn is_patronymic_particle(word: &str) -> bool {
+    PATRONYMIC_PARTICLES
+        .iter()
+        .any(|particle| particle.eq_ignore_ascii_case(word))
+}
+
+// Returns the index of the first word of a patronymic immediately preceding
+// the surname, or `surname_index` if there is none, always leaving at least
+// a given name or initial
+pub fn find_patronymic_index(words: &[NamePart], surname_index: usize) -> usize {
+    if surname_index < 2 {
+        return surname_index;
+    }
+
+    let word = &words[surname_index - 1];
+    if word.is_namelike() && is_patronymic(word.word) {
+        return surname_index - 1;
+    }
+
+    // Chains like "bin Abdulaziz bin Abdul Rahman" (the last name in such a
+    // chain will have been treated as the surname)
+    let mut index = surname_index;
+    while index >= 3
+        && is_patronymic_particle(words[index - 2].word)
+        && words[index - 1].is_namelike()
+    {
+        index -= 2;
+    }
+    index
+}
+
+#[cfg(test)]
+mod tests {
+    use super::super::namepart::{Location, NamePart};
+    use super::*;
+
+    #[test]
+    fn patronymics() {
+        assert!(is_patronymic("Ivanovich"));
+        assert!(is_patronymic("Petrovna"));
+        assert!(is_patronymic("Иванович"));
+        assert!(is_patronymic("Stefánsson"));
+        assert!(is_patronymic("Jónsdóttir"));
+        assert!(is_patronymic("Magnússon"));
+        assert!(is_patronymic("Gudmundsdottir"));
+        assert!(!is_patronymic("Johnson"));
+        assert!(!is_patronymic("Besson"));
+        assert!(!is_patronymic("Masson"));
+        assert!(!is_patronymic("Samsson"));
+        assert!(!is_patronymic("Karlsson"));
+        assert!(!is_patronymic("Magnusson"));
+        assert!(!is_patronymic("Danielsson"));
+        assert!(!is_patronymic("Jakobsson"));
+        assert!(!is_patronymic("Gunnarsson"));
+        assert!(is_patronymic("Gunnarsdóttir"));
+        assert!(is_patronymic("Ólafsson"));
+        assert!(is_patronymic("Kjartansson"));
+        assert!(!is_patronymic("Ivan"));
+    }
+
+    #[test]
+    fn patronymic_index() {
+        let parts: Vec<_> =
+            NamePart::all_from_text("Ivan Ivanovich Petrov", true, Location::Start).collect();
+        assert_eq!(1, find_patronymic_index(&*parts, 2));
+
+        let parts: Vec<_> =
+            NamePart::all_from_text("Ivanovich Petrov", true, Location::Start).collect();
+        assert_eq!(1, find_patronymic_index(&*parts, 1));
+
+        let parts: Vec<_> =
+            NamePart::all_from_text("Mohammed bin Salman Al Saud", true, Location::Start).collect();
+        assert_eq!(1, find_patronymic_index(&*parts, 3));
+
+        let parts: Vec<_> =
+            NamePart::all_from_text("John Quincy Adams", true, Location::Start).collect();
+        assert_eq!(2, find_patronymic_index(&*parts, 2));
+    }
+}
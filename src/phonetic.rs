@@ -0,0 +1,945 @@
+use super::utils::{transliterate, uppercase_if_alpha};
+use super::Name;
+
+/// Phonetic encodings of a word, for matching names spelled by ear.
+#[derive(Clone, Debug, Eq, PartialEq, Hash)]
+pub struct PhoneticKey {
+    /// American Soundex, e.g. "S530" for "Smith".
+    pub soundex: String,
+    /// Primary and alternate Double Metaphone encodings, e.g. "SM0" and
+    /// "XMT" for "Smith" (often the same).
+    pub metaphone: (String, String),
+    /// New York State Identification and Intelligence System encoding, e.g.
+    /// "SNAT" for "Smith".
+    pub nysiis: String,
+}
+
+/// Phonetic encodings of a name's surname and given name.
+///
+/// Returned by `Name::phonetic_keys`.
+#[derive(Clone, Debug, Eq, PartialEq, Hash)]
+pub struct PhoneticKeys {
+    pub surname: PhoneticKey,
+    /// Absent when only a first initial is known.
+    pub given_name: Option<PhoneticKey>,
+}
+
+impl PhoneticKey {
+    fn for_word(word: &str) -> PhoneticKey {
+        let letters = ascii_uppercase(word);
+
+        PhoneticKey {
+            soundex: soundex(&letters),
+            metaphone: double_metaphone(&letters),
+            nysiis: nysiis(&letters),
+        }
+    }
+}
+
+impl Name {
+    /// Standard phonetic encodings of the surname and given name, after
+    /// transliteration to ASCII.
+    ///
+    /// Useful for grouping names spelled differently by ear, as in "Schmidt"
+    /// and "Smith" or "Catherine" and "Kathryn" (see also
+    /// `MatchOptions::allow_phonetic_surnames`).
+    ///
+    /// ```
+    /// use human_name::Name;
+    ///
+    /// let catherine = Name::parse("Catherine Schmidt").unwrap().phonetic_keys();
+    /// let kathryn = Name::parse("Kathryn Smith").unwrap().phonetic_keys();
+    ///
+    /// assert_eq!("S530", kathryn.surname.soundex);
+    /// assert_eq!("XMT", catherine.surname.metaphone.0);
+    /// assert_eq!("XMT", kathryn.surname.metaphone.1);
+    /// assert_eq!(
+    ///     catherine.given_name.unwrap().metaphone,
+    ///     kathryn.given_name.unwrap().metaphone
+    /// );
+    /// ```
+    pub fn phonetic_keys(&self) -> PhoneticKeys {
+        PhoneticKeys {
+            surname: PhoneticKey::for_word(&self.surname()),
+            given_name: self.given_name().map(PhoneticKey::for_word),
+        }
+    }
+}
+
+// Whether any Double Metaphone encoding of one matches one of the other
+pub fn sounds_alike(a: &str, b: &str) -> bool {
+    let (a_primary, a_alternate) = double_metaphone(&ascii_uppercase(a));
+    let (b_primary, b_alternate) = double_metaphone(&ascii_uppercase(b));
+
+    !a_primary.is_empty()
+        && (a_primary == b_primary
+            || a_primary == b_alternate
+            || a_alternate == b_primary
+            || a_alternate == b_alternate)
+}
+
+// Uppercase ASCII letters, with words separated by single spaces, which
+// Double Metaphone uses to recognize, e.g., "Van " and "Von " prefixes
+fn ascii_uppercase(text: &str) -> String {
+    let mut result = String::with_capacity(text.len());
+
+    for c in text.chars().flat_map(transliterate) {
+        match uppercase_if_alpha(c) {
+            Some(c) if c.is_ascii_uppercase() => result.push(c),
+            _ if c.is_whitespace() && !result.is_empty() && !result.ends_with(' ') => {
+                result.push(' ')
+            }
+            _ => {}
+        }
+    }
+
+    let len = result.trim_end().len();
+    result.truncate(len);
+    result
+}
+
+fn soundex_digit(c: u8) -> Option<u8> {
+    match c {
+        b'B' | b'F' | b'P' | b'V' => Some(b'1'),
+        b'C' | b'G' | b'J' | b'K' | b'Q' | b'S' | b'X' | b'Z' => Some(b'2'),
+        b'D' | b'T' => Some(b'3'),
+        b'L' => Some(b'4'),
+        b'M' | b'N' => Some(b'5'),
+        b'R' => Some(b'6'),
+        _ => None,
+    }
+}
+
+pub fn soundex(word: &str) -> String {
+    let mut letters = word.bytes().filter(u8::is_ascii_uppercase);
+
+    let first = match letters.next() {
+        Some(c) => c,
+        None => return String::new(),
+    };
+
+    let mut code = vec![first];
+    let mut prev = soundex_digit(first);
+
+    for c in letters {
+        let digit = soundex_digit(c);
+        if digit.is_some() && digit != prev {
+            code.push(digit.unwrap());
+            if code.len() == 4 {
+                break;
+            }
+        }
+
+        // "H" and "W" don't separate letters with the same code, but vowels do
+        if c != b'H' && c != b'W' {
+            prev = digit;
+        }
+    }
+
+    while code.len() < 4 {
+        code.push(b'0');
+    }
+
+    String::from_utf8(code).unwrap()
+}
+
+fn is_nysiis_vowel(c: u8) -> bool {
+    match c {
+        b'A' | b'E' | b'I' | b'O' | b'U' => true,
+        _ => false,
+    }
+}
+
+pub fn nysiis(word: &str) -> String {
+    let mut name: Vec<u8> = word.bytes().filter(u8::is_ascii_uppercase).collect();
+    if name.is_empty() {
+        return String::new();
+    }
+
+    // Translate the first characters
+    if name.starts_with(b"MAC") {
+        name[1] = b'C';
+    } else if name.starts_with(b"KN") {
+        name.remove(0);
+    } else if name.starts_with(b"K") {
+        name[0] = b'C';
+    } else if name.starts_with(b"PH") || name.starts_with(b"PF") {
+        name[0] = b'F';
+        name[1] = b'F';
+    } else if name.starts_with(b"SCH") {
+        name[1] = b'S';
+        name[2] = b'S';
+    }
+
+    // Translate the last characters
+    let len = name.len();
+    if name.ends_with(b"EE") || name.ends_with(b"IE") {
+        name.truncate(len - 2);
+        name.push(b'Y');
+    } else if [&b"DT"[..], b"RT", b"RD", b"NT", b"ND"]
+        .iter()
+        .any(|suffix| name.ends_with(suffix))
+    {
+        name.truncate(len - 2);
+        name.push(b'D');
+    }
+
+    let mut key = vec![name[0]];
+    let mut i = 1;
+
+    while i < name.len() {
+        let c = name[i];
+        let next = name.get(i + 1).cloned();
+        let prev = name[i - 1];
+
+        let translated: &[u8] = match c {
+            b'E' if next == Some(b'V') => {
+                i += 1;
+                b"AF"
+            }
+            _ if is_nysiis_vowel(c) => b"A",
+            b'Q' => b"G",
+            b'Z' => b"S",
+            b'M' => b"N",
+            b'K' if next == Some(b'N') => b"N",
+            b'K' => b"C",
+            b'S' if name[i + 1..].starts_with(b"CH") => {
+                i += 2;
+                b"SS"
+            }
+            b'P' if next == Some(b'H') => {
+                i += 1;
+                b"F"
+            }
+            b'H' if !is_nysiis_vowel(prev) || !next.map_or(false, is_nysiis_vowel) => {
+                if is_nysiis_vowel(prev) {
+                    b"A"
+                } else {
+                    &name[i - 1..i]
+                }
+            }
+            b'W' if is_nysiis_vowel(prev) => &name[i - 1..i],
+            _ => &name[i..i + 1],
+        };
+
+        if key.last() != translated.last() {
+            key.extend_from_slice(translated);
+        }
+        i += 1;
+    }
+
+    if key.len() > 1 && key.ends_with(b"S") {
+        key.pop();
+    }
+    if key.ends_with(b"AY") {
+        key.remove(key.len() - 2);
+    }
+    if key.len() > 1 && key.ends_with(b"A") {
+        key.pop();
+    }
+
+    String::from_utf8(key).unwrap()
+}
+
+const METAPHONE_LEN: usize = 4;
+
+// Lawrence Philips' Double Metaphone, following the Apache Commons Codec
+// implementation, over uppercase ASCII letters and spaces
+pub fn double_metaphone(word: &str) -> (String, String) {
+    let mut metaphone = DoubleMetaphone {
+        word: word.as_bytes(),
+        primary: String::new(),
+        alternate: String::new(),
+        slavo_germanic: word.contains('W') || word.contains('K') || word.contains("CZ"),
+    };
+    metaphone.encode();
+    (metaphone.primary, metaphone.alternate)
+}
+
+struct DoubleMetaphone<'a> {
+    word: &'a [u8],
+    primary: String,
+    alternate: String,
+    slavo_germanic: bool,
+}
+
+impl<'a> DoubleMetaphone<'a> {
+    fn encode(&mut self) {
+        let silent_start = ["GN", "KN", "PN", "WR", "PS"]
+            .iter()
+            .any(|start| self.word.starts_with(start.as_bytes()));
+        let mut i = if silent_start { 1 } else { 0 };
+
+        while !self.is_complete() && i < self.word.len() {
+            i = match self.word[i] {
+                b'A' | b'E' | b'I' | b'O' | b'U' | b'Y' => {
+                    if i == 0 {
+                        self.add("A");
+                    }
+                    i + 1
+                }
+                b'B' => {
+                    self.add("P");
+                    self.skip_double(i, b'B')
+                }
+                b'C' => self.handle_c(i),
+                b'D' => self.handle_d(i),
+                b'F' => {
+                    self.add("F");
+                    self.skip_double(i, b'F')
+                }
+                b'G' => self.handle_g(i),
+                b'H' => self.handle_h(i),
+                b'J' => self.handle_j(i),
+                b'K' => {
+                    self.add("K");
+                    self.skip_double(i, b'K')
+                }
+                b'L' => self.handle_l(i),
+                b'M' => {
+                    self.add("M");
+                    if self.at(i + 1) == b'M'
+                        || (self.matches(i as isize - 1, &["UMB"])
+                            && (i + 2 == self.word.len() || self.matches(i as isize + 2, &["ER"])))
+                    {
+                        i + 2
+                    } else {
+                        i + 1
+                    }
+                }
+                b'N' => {
+                    self.add("N");
+                    self.skip_double(i, b'N')
+                }
+                b'P' => self.handle_p(i),
+                b'Q' => {
+                    self.add("K");
+                    self.skip_double(i, b'Q')
+                }
+                b'R' => self.handle_r(i),
+                b'S' => self.handle_s(i),
+                b'T' => self.handle_t(i),
+                b'V' => {
+                    self.add("F");
+                    self.skip_double(i, b'V')
+                }
+                b'W' => self.handle_w(i),
+                b'X' => self.handle_x(i),
+                b'Z' => self.handle_z(i),
+                _ => i + 1,
+            };
+        }
+    }
+
+    fn is_complete(&self) -> bool {
+        self.primary.len() >= METAPHONE_LEN && self.alternate.len() >= METAPHONE_LEN
+    }
+
+    fn add(&mut self, code: &str) {
+        self.add_both(code, code);
+    }
+
+    fn add_both(&mut self, primary: &str, alternate: &str) {
+        self.add_primary(primary);
+        self.add_alternate(alternate);
+    }
+
+    fn add_primary(&mut self, code: &str) {
+        let remaining = METAPHONE_LEN.saturating_sub(self.primary.len());
+        self.primary.push_str(&code[..code.len().min(remaining)]);
+    }
+
+    fn add_alternate(&mut self, code: &str) {
+        let remaining = METAPHONE_LEN.saturating_sub(self.alternate.len());
+        self.alternate.push_str(&code[..code.len().min(remaining)]);
+    }
+
+    // Zero outside the word
+    fn at(&self, i: usize) -> u8 {
+        self.word.get(i).cloned().unwrap_or(0)
+    }
+
+    fn before(&self, i: usize, by: usize) -> u8 {
+        if i >= by {
+            self.word[i - by]
+        } else {
+            0
+        }
+    }
+
+    fn is_vowel_at(&self, i: isize) -> bool {
+        i >= 0 && is_metaphone_vowel(self.at(i as usize))
+    }
+
+    // Whether the word has one of the given strings at `start`
+    fn matches(&self, start: isize, options: &[&str]) -> bool {
+        if start < 0 {
+            return false;
+        }
+
+        let start = start as usize;
+        options.iter().any(|option| {
+            let end = start + option.len();
+            end <= self.word.len() && &self.word[start..end] == option.as_bytes()
+        })
+    }
+
+    fn skip_double(&self, i: usize, c: u8) -> usize {
+        if self.at(i + 1) == c {
+            i + 2
+        } else {
+            i + 1
+        }
+    }
+
+    fn is_germanic(&self) -> bool {
+        self.matches(0, &["VAN ", "VON ", "SCH"])
+    }
+
+    fn handle_c(&mut self, i: usize) -> usize {
+        let s = i as isize;
+
+        if self.c_is_k(i) {
+            self.add("K");
+            i + 2
+        } else if i == 0 && self.matches(s, &["CAESAR"]) {
+            self.add("S");
+            i + 2
+        } else if self.matches(s, &["CH"]) {
+            self.handle_ch(i)
+        } else if self.matches(s, &["CZ"]) && !self.matches(s - 2, &["WICZ"]) {
+            // "Czerny"
+            self.add_both("S", "X");
+            i + 2
+        } else if self.matches(s + 1, &["CIA"]) {
+            // "Focaccia"
+            self.add("X");
+            i + 3
+        } else if self.matches(s, &["CC"]) && !(i == 1 && self.at(0) == b'M') {
+            // But not "McClelland"
+            if self.matches(s + 2, &["I", "E", "H"]) && !self.matches(s + 2, &["HU"]) {
+                if (i == 1 && self.at(0) == b'A') || self.matches(s - 1, &["UCCEE", "UCCES"]) {
+                    // "Accident", "succeed"
+                    self.add("KS");
+                } else {
+                    // "Bacci", "Bertucci"
+                    self.add("X");
+                }
+                i + 3
+            } else {
+                self.add("K");
+                i + 2
+            }
+        } else if self.matches(s, &["CK", "CG", "CQ"]) {
+            self.add("K");
+            i + 2
+        } else if self.matches(s, &["CI", "CE", "CY"]) {
+            if self.matches(s, &["CIO", "CIE", "CIA"]) {
+                self.add_both("S", "X");
+            } else {
+                self.add("S");
+            }
+            i + 2
+        } else {
+            self.add("K");
+            if self.matches(s + 1, &[" C", " Q", " G"]) {
+                // "Mac Caffrey", "Mac Gregor"
+                i + 3
+            } else if self.matches(s + 1, &["C", "K", "Q"]) && !self.matches(s + 1, &["CE", "CI"]) {
+                i + 2
+            } else {
+                i + 1
+            }
+        }
+    }
+
+    // E.g. "Chianti", "Bacher", but not "Macher"
+    fn c_is_k(&self, i: usize) -> bool {
+        let s = i as isize;
+
+        if self.matches(s, &["CHIA"]) {
+            true
+        } else if i <= 1 || self.is_vowel_at(s - 2) || !self.matches(s - 1, &["ACH"]) {
+            false
+        } else {
+            let c = self.at(i + 2);
+            (c != b'I' && c != b'E') || self.matches(s - 2, &["BACHER", "MACHER"])
+        }
+    }
+
+    fn handle_ch(&mut self, i: usize) -> usize {
+        let s = i as isize;
+
+        if i > 0 && self.matches(s, &["CHAE"]) {
+            // "Michael"
+            self.add_both("K", "X");
+        } else if i == 0
+            && (self.matches(1, &["HARAC", "HARIS"])
+                || self.matches(1, &["HOR", "HYM", "HIA", "HEM"]))
+            && !self.matches(0, &["CHORE"])
+        {
+            // Greek roots, e.g. "chorus"
+            self.add("K");
+        } else if self.is_germanic()
+            || self.matches(s - 2, &["ORCHES", "ARCHIT", "ORCHID"])
+            || self.matches(s + 2, &["T", "S"])
+            || ((i == 0 || self.matches(s - 1, &["A", "O", "U", "E"]))
+                && (self.matches(s + 2, &["L", "R", "N", "M", "B", "H", "F", "V", "W", " "])
+                    || i + 2 == self.word.len()))
+        {
+            // Germanic, Greek, or otherwise "ch" for "kh"
+            self.add("K");
+        } else if i > 0 {
+            if self.matches(0, &["MC"]) {
+                self.add("K");
+            } else {
+                self.add_both("X", "K");
+            }
+        } else {
+            self.add("X");
+        }
+
+        i + 2
+    }
+
+    fn handle_d(&mut self, i: usize) -> usize {
+        let s = i as isize;
+
+        if self.matches(s, &["DG"]) {
+            if self.matches(s + 2, &["I", "E", "Y"]) {
+                // "Edge"
+                self.add("J");
+                i + 3
+            } else {
+                // "Edgar"
+                self.add("TK");
+                i + 2
+            }
+        } else if self.matches(s, &["DT", "DD"]) {
+            self.add("T");
+            i + 2
+        } else {
+            self.add("T");
+            i + 1
+        }
+    }
+
+    fn handle_g(&mut self, i: usize) -> usize {
+        let s = i as isize;
+        let next = self.at(i + 1);
+
+        if next == b'H' {
+            self.handle_gh(i)
+        } else if next == b'N' {
+            if i == 1 && self.is_vowel_at(0) && !self.slavo_germanic {
+                self.add_both("KN", "N");
+            } else if !self.matches(s + 2, &["EY"]) && !self.slavo_germanic {
+                self.add_both("N", "KN");
+            } else {
+                self.add("KN");
+            }
+            i + 2
+        } else if self.matches(s + 1, &["LI"]) && !self.slavo_germanic {
+            // "Tagliaro"
+            self.add_both("KL", "L");
+            i + 2
+        } else if i == 0
+            && (next == b'Y'
+                || self.matches(
+                    1,
+                    &[
+                        "ES", "EP", "EB", "EL", "EY", "IB", "IL", "IN", "IE", "EI", "ER",
+                    ],
+                ))
+        {
+            // "-ges-", "-gep-", "-gel-", "-gie-" at the beginning
+            self.add_both("K", "J");
+            i + 2
+        } else if (self.matches(s + 1, &["ER"]) || next == b'Y')
+            && !self.matches(0, &["DANGER", "RANGER", "MANGER"])
+            && !self.matches(s - 1, &["E", "I"])
+            && !self.matches(s - 1, &["RGY", "OGY"])
+        {
+            // "-ger-", "-gy-"
+            self.add_both("K", "J");
+            i + 2
+        } else if self.matches(s + 1, &["E", "I", "Y"]) || self.matches(s - 1, &["AGGI", "OGGI"]) {
+            // Italian, e.g. "Biaggi"
+            if self.is_germanic() || self.matches(s + 1, &["ET"]) {
+                self.add("K");
+            } else if self.matches(s + 1, &["IER"]) {
+                self.add("J");
+            } else {
+                self.add_both("J", "K");
+            }
+            i + 2
+        } else if next == b'G' {
+            self.add("K");
+            i + 2
+        } else {
+            self.add("K");
+            i + 1
+        }
+    }
+
+    fn handle_gh(&mut self, i: usize) -> usize {
+        let s = i as isize;
+
+        if i > 0 && !self.is_vowel_at(s - 1) {
+            self.add("K");
+        } else if i == 0 {
+            // "Ghislane", "Ghiradelli"
+            if self.at(i + 2) == b'I' {
+                self.add("J");
+            } else {
+                self.add("K");
+            }
+        } else if (i > 1 && self.matches(s - 2, &["B", "H", "D"]))
+            || (i > 2 && self.matches(s - 3, &["B", "H", "D"]))
+            || (i > 3 && self.matches(s - 4, &["B", "H"]))
+        {
+            // Parker's rule, e.g. "Hugh"
+        } else if i > 2
+            && self.before(i, 1) == b'U'
+            && self.matches(s - 3, &["C", "G", "L", "R", "T"])
+        {
+            // "Laugh", "McLaughlin", "cough", "tough"
+            self.add("F");
+        } else if i > 0 && self.before(i, 1) != b'I' {
+            self.add("K");
+        }
+
+        i + 2
+    }
+
+    fn handle_h(&mut self, i: usize) -> usize {
+        // Only keep if first and before a vowel, or between two vowels
+        if (i == 0 || self.is_vowel_at(i as isize - 1)) && self.is_vowel_at(i as isize + 1) {
+            self.add("H");
+            i + 2
+        } else {
+            i + 1
+        }
+    }
+
+    fn handle_j(&mut self, i: usize) -> usize {
+        let s = i as isize;
+
+        if self.matches(s, &["JOSE"]) || self.matches(0, &["SAN "]) {
+            // Spanish, e.g. "Jose", "San Jacinto"
+            if (i == 0 && (self.at(i + 4) == b' ' || self.word.len() == 4))
+                || self.matches(0, &["SAN "])
+            {
+                self.add("H");
+            } else {
+                self.add_both("J", "H");
+            }
+            return i + 1;
+        }
+
+        if i == 0 {
+            // "Yankelovich", "Jankelowicz"
+            self.add_both("J", "A");
+        } else if self.is_vowel_at(s - 1)
+            && !self.slavo_germanic
+            && (self.at(i + 1) == b'A' || self.at(i + 1) == b'O')
+        {
+            // Spanish pronunciation of, e.g. "bajador"
+            self.add_both("J", "H");
+        } else if i + 1 == self.word.len() {
+            self.add_primary("J");
+        } else if !self.matches(s + 1, &["L", "T", "K", "S", "N", "M", "B", "Z"])
+            && !self.matches(s - 1, &["S", "K", "L"])
+        {
+            self.add("J");
+        }
+
+        self.skip_double(i, b'J')
+    }
+
+    fn handle_l(&mut self, i: usize) -> usize {
+        let s = i as isize;
+
+        if self.at(i + 1) == b'L' {
+            let len = self.word.len() as isize;
+            let spanish = (s == len - 3 && self.matches(s - 1, &["ILLO", "ILLA", "ALLE"]))
+                || ((self.matches(len - 2, &["AS", "OS"]) || self.matches(len - 1, &["A", "O"]))
+                    && self.matches(s - 1, &["ALLE"]));
+
+            // Spanish, e.g. "Cabrillo", "Gallegos"
+            if spanish {
+                self.add_primary("L");
+            } else {
+                self.add("L");
+            }
+            i + 2
+        } else {
+            self.add("L");
+            i + 1
+        }
+    }
+
+    fn handle_p(&mut self, i: usize) -> usize {
+        if self.at(i + 1) == b'H' {
+            self.add("F");
+            i + 2
+        } else {
+            self.add("P");
+            if self.matches(i as isize + 1, &["P", "B"]) {
+                i + 2
+            } else {
+                i + 1
+            }
+        }
+    }
+
+    fn handle_r(&mut self, i: usize) -> usize {
+        let s = i as isize;
+
+        // French, e.g. "Rogier", but exclude "Hochmeier"
+        if i + 1 == self.word.len()
+            && !self.slavo_germanic
+            && self.matches(s - 2, &["IE"])
+            && !self.matches(s - 4, &["ME", "MA"])
+        {
+            self.add_alternate("R");
+        } else {
+            self.add("R");
+        }
+
+        self.skip_double(i, b'R')
+    }
+
+    fn handle_s(&mut self, i: usize) -> usize {
+        let s = i as isize;
+
+        if self.matches(s - 1, &["ISL", "YSL"]) {
+            // "Island", "Carlisle", "Carlysle"
+            i + 1
+        } else if i == 0 && self.matches(s, &["SUGAR"]) {
+            self.add_both("X", "S");
+            i + 1
+        } else if self.matches(s, &["SH"]) {
+            if self.matches(s + 1, &["HEIM", "HOEK", "HOLM", "HOLZ"]) {
+                // Germanic
+                self.add("S");
+            } else {
+                self.add("X");
+            }
+            i + 2
+        } else if self.matches(s, &["SIO", "SIA"]) || self.matches(s, &["SIAN"]) {
+            // Italian and Armenian
+            if self.slavo_germanic {
+                self.add("S");
+            } else {
+                self.add_both("S", "X");
+            }
+            i + 3
+        } else if (i == 0 && self.matches(s + 1, &["M", "N", "L", "W"]))
+            || self.matches(s + 1, &["Z"])
+        {
+            // German and anglicized, e.g. "Smith" and "Schmidt", "Snider" and
+            // "Schneider", and Slavic "sz"
+            self.add_both("S", "X");
+            self.skip_double(i, b'Z')
+        } else if self.matches(s, &["SC"]) {
+            self.handle_sc(i)
+        } else {
+            // French, e.g. "Resnais", "Artois"
+            if i + 1 == self.word.len() && self.matches(s - 2, &["AI", "OI"]) {
+                self.add_alternate("S");
+            } else {
+                self.add("S");
+            }
+
+            if self.matches(s + 1, &["S", "Z"]) {
+                i + 2
+            } else {
+                i + 1
+            }
+        }
+    }
+
+    fn handle_sc(&mut self, i: usize) -> usize {
+        let s = i as isize;
+
+        if self.at(i + 2) == b'H' {
+            if self.matches(s + 3, &["OO", "ER", "EN", "UY", "ED", "EM"]) {
+                // Dutch, e.g. "School", "Schenker"
+                if self.matches(s + 3, &["ER", "EN"]) {
+                    self.add_both("X", "SK");
+                } else {
+                    self.add("SK");
+                }
+            } else if i == 0 && !self.is_vowel_at(3) && self.at(3) != b'W' {
+                self.add_both("X", "S");
+            } else {
+                self.add("X");
+            }
+        } else if self.matches(s + 2, &["I", "E", "Y"]) {
+            self.add("S");
+        } else {
+            self.add("SK");
+        }
+
+        i + 3
+    }
+
+    fn handle_t(&mut self, i: usize) -> usize {
+        let s = i as isize;
+
+        if self.matches(s, &["TION"]) || self.matches(s, &["TIA", "TCH"]) {
+            self.add("X");
+            i + 3
+        } else if self.matches(s, &["TH"]) || self.matches(s, &["TTH"]) {
+            // "Thomas", "Thames", or Germanic
+            if self.matches(s + 2, &["OM", "AM"]) || self.is_germanic() {
+                self.add("T");
+            } else {
+                self.add_both("0", "T");
+            }
+            i + 2
+        } else {
+            self.add("T");
+            if self.matches(s + 1, &["T", "D"]) {
+                i + 2
+            } else {
+                i + 1
+            }
+        }
+    }
+
+    fn handle_w(&mut self, i: usize) -> usize {
+        let s = i as isize;
+
+        if self.matches(s, &["WR"]) {
+            self.add("R");
+            i + 2
+        } else if i == 0 && (self.is_vowel_at(1) || self.matches(s, &["WH"])) {
+            // "Wasserman" should match "Vasserman"
+            if self.is_vowel_at(1) {
+                self.add_both("A", "F");
+            } else {
+                self.add("A");
+            }
+            i + 1
+        } else if (i + 1 == self.word.len() && self.is_vowel_at(s - 1))
+            || self.matches(s - 1, &["EWSKI", "EWSKY", "OWSKI", "OWSKY"])
+            || self.matches(0, &["SCH"])
+        {
+            // "Arnow" should match "Arnoff"
+            self.add_alternate("F");
+            i + 1
+        } else if self.matches(s, &["WICZ", "WITZ"]) {
+            // Polish, e.g. "Filipowicz"
+            self.add_both("TS", "FX");
+            i + 4
+        } else {
+            i + 1
+        }
+    }
+
+    fn handle_x(&mut self, i: usize) -> usize {
+        let s = i as isize;
+
+        if i == 0 {
+            self.add("S");
+            return i + 1;
+        }
+
+        // French, e.g. "Breaux"
+        if !(i + 1 == self.word.len()
+            && (self.matches(s - 3, &["IAU", "EAU"]) || self.matches(s - 2, &["AU", "OU"])))
+        {
+            self.add("KS");
+        }
+
+        if self.matches(s + 1, &["C", "X"]) {
+            i + 2
+        } else {
+            i + 1
+        }
+    }
+
+    fn handle_z(&mut self, i: usize) -> usize {
+        let s = i as isize;
+
+        if self.at(i + 1) == b'H' {
+            // Chinese pinyin, e.g. "Zhao"
+            self.add("J");
+            return i + 2;
+        }
+
+        if self.matches(s + 1, &["ZO", "ZI", "ZA"])
+            || (self.slavo_germanic && i > 0 && self.before(i, 1) != b'T')
+        {
+            self.add_both("S", "TS");
+        } else {
+            self.add("S");
+        }
+
+        self.skip_double(i, b'Z')
+    }
+}
+
+fn is_metaphone_vowel(c: u8) -> bool {
+    match c {
+        b'A' | b'E' | b'I' | b'O' | b'U' | b'Y' => true,
+        _ => false,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn metaphone(word: &str) -> (String, String) {
+        double_metaphone(&ascii_uppercase(word))
+    }
+
+    #[test]
+    fn soundexes() {
+        assert_eq!("R163", soundex("ROBERT"));
+        assert_eq!("R163", soundex("RUPERT"));
+        assert_eq!("A261", soundex("ASHCRAFT"));
+        assert_eq!("T522", soundex("TYMCZAK"));
+        assert_eq!("P236", soundex("PFISTER"));
+        assert_eq!("L000", soundex("LEE"));
+        assert_eq!("", soundex(""));
+    }
+
+    #[test]
+    fn nysiises() {
+        assert_eq!("WARTY", nysiis("WORTHY"));
+        assert_eq!("OGAT", nysiis("OGATA"));
+        assert_eq!("MANTGANARY", nysiis("MONTGOMERY"));
+        assert_eq!("CASTAL", nysiis("COSTALES"));
+        assert_eq!("T", nysiis("TU"));
+        assert_eq!("NAGT", nysiis("KNIGHT"));
+        assert_eq!("MATCAL", nysiis("MITCHELL"));
+        assert_eq!("", nysiis(""));
+    }
+
+    #[test]
+    fn double_metaphones() {
+        assert_eq!(("SM0".into(), "XMT".into()), metaphone("Smith"));
+        assert_eq!(("XMT".into(), "SMT".into()), metaphone("Schmidt"));
+        assert_eq!(("K0RN".into(), "KTRN".into()), metaphone("Catherine"));
+        assert_eq!(("K0RN".into(), "KTRN".into()), metaphone("Kathryn"));
+        assert_eq!(("TMS".into(), "TMS".into()), metaphone("Thomas"));
+        assert_eq!(("SF".into(), "SFR".into()), metaphone("Xavier"));
+        assert_eq!(("HS".into(), "HS".into()), metaphone("José"));
+        assert_eq!(("MKL".into(), "MXL".into()), metaphone("Michael"));
+        assert_eq!(("FLPT".into(), "FLPF".into()), metaphone("Filipowicz"));
+        assert_eq!(("".into(), "".into()), metaphone(""));
+    }
+
+    #[test]
+    fn alike() {
+        assert!(sounds_alike("Schmidt", "Smith"));
+        assert!(sounds_alike("Catherine", "Kathryn"));
+        assert!(sounds_alike("Schneider", "Snider"));
+        assert!(!sounds_alike("Smith", "Jones"));
+        assert!(!sounds_alike("", ""));
+    }
+}
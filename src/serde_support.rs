@@ -0,0 +1,306 @@
+use super::namepart::{Location, NamePart};
+use super::parse::{ParseError, Titles};
+use super::suffix;
+use super::{component_words, Name};
+use serde::de::{Deserializer, Error};
+use serde::ser::Serializer;
+use serde::{Deserialize, Serialize};
+use smallvec::SmallVec;
+use std::borrow::Cow;
+
+// The same components as `to_json`, except that titles are listed rather
+// than joined, since a title may have more than one word
+#[derive(Serialize, Deserialize)]
+struct Components<'a> {
+    surname: Cow<'a, str>,
+    first_initial: char,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    given_name: Option<Cow<'a, str>>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    middle_initials: Option<Cow<'a, str>>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    middle_names: Option<Cow<'a, str>>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    patronymic: Option<Cow<'a, str>>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    paternal_surname: Option<Cow<'a, str>>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    maternal_surname: Option<Cow<'a, str>>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    suffix: Option<Cow<'a, str>>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    nickname: Option<Cow<'a, str>>,
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    titles: Vec<Cow<'a, str>>,
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    postfix_titles: Vec<Cow<'a, str>>,
+}
+
+/// Serializes a name into parsed components, as with `to_json`, but with
+/// titles as lists.
+///
+/// ```
+/// # extern crate human_name;
+/// # extern crate serde_json;
+/// #
+/// use human_name::Name;
+///
+/// # fn main() {
+/// let name = Name::parse("Dr. John Allen Q. MacDonald, Jr.").unwrap();
+/// let json = serde_json::to_string(&name).unwrap();
+/// assert_eq!(
+///   r#"{"surname":"MacDonald","first_initial":"J","given_name":"John","middle_initials":"AQ","middle_names":"Allen","suffix":"Jr.","titles":["Dr."]}"#,
+///   json
+/// );
+///
+/// let deserialized: Name = serde_json::from_str(&json).unwrap();
+/// assert_eq!(name.display_full(), deserialized.display_full());
+/// assert_eq!(name.hash, deserialized.hash);
+/// # }
+/// ```
+impl Serialize for Name {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        Components {
+            surname: self.surname(),
+            first_initial: self.first_initial(),
+            given_name: self.given_name().map(Cow::Borrowed),
+            middle_initials: self.middle_initials().map(Cow::Borrowed),
+            middle_names: self.middle_name(),
+            patronymic: self.patronymic(),
+            paternal_surname: self.paternal_surname(),
+            maternal_surname: self.maternal_surname(),
+            suffix: self.suffix().map(Cow::Borrowed),
+            nickname: self.nickname().map(Cow::Borrowed),
+            titles: self
+                .prefix_titles
+                .iter()
+                .map(|t| Cow::Borrowed(&**t))
+                .collect(),
+            postfix_titles: self
+                .postfix_titles
+                .iter()
+                .map(|t| Cow::Borrowed(&**t))
+                .collect(),
+        }
+        .serialize(serializer)
+    }
+}
+
+/// Rebuilds a name from the components it was serialized into, without
+/// parsing it again.
+impl<'de> Deserialize<'de> for Name {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Name, D::Error> {
+        let components = Components::deserialize(deserializer)?;
+        Name::from_serialized(&components).map_err(D::Error::custom)
+    }
+}
+
+impl Name {
+    fn from_serialized(components: &Components) -> Result<Name, &'static str> {
+        let mut initials = String::new();
+        initials.push(components.first_initial);
+        if let Some(ref middle_initials) = components.middle_initials {
+            initials.push_str(middle_initials);
+        }
+        if !initials.chars().all(char::is_alphabetic) {
+            return Err("initials must be letters");
+        }
+
+        // Each name must be made up of names or initials, as with
+        // `from_components`
+        if let Some(ref given_name) = components.given_name {
+            check_component(given_name)?;
+        }
+        if let Some(ref middle_names) = components.middle_names {
+            check_component(middle_names)?;
+        }
+        if let Some(ref patronymic) = components.patronymic {
+            check_component(patronymic)?;
+        }
+        if !check_component(&components.surname)?
+            .iter()
+            .any(NamePart::is_namelike)
+        {
+            return Err("no surname");
+        }
+
+        // The given name isn't always the first, as in "T. Boone Pickens",
+        // so we align each name we know with its initials (keeping the given
+        // name whole, since it may have more than one word, as with
+        // `from_components`)
+        let names = components
+            .given_name
+            .as_ref()
+            .map(|name| &**name)
+            .into_iter()
+            .chain(
+                components
+                    .middle_names
+                    .iter()
+                    .flat_map(|names| names.split_whitespace()),
+            );
+
+        let mut words: Vec<NamePart> = Vec::new();
+        let mut unaligned = &initials[..];
+
+        for name in names {
+            let part = NamePart::from_name(name);
+            let mut name_initials = String::new();
+            part.with_initials(|c| name_initials.push(c));
+
+            let start = unaligned
+                .char_indices()
+                .map(|(i, _)| i)
+                .find(|&i| unaligned[i..].starts_with(&*name_initials))
+                .ok_or("names don't match initials")?;
+
+            words.extend(initial_parts(&unaligned[..start]));
+            words.push(part);
+            unaligned = &unaligned[start + name_initials.len()..];
+        }
+        words.extend(initial_parts(unaligned));
+
+        // Unless the patronymic doubles as the surname, as with Icelandic names
+        if let Some(ref patronymic) = components.patronymic {
+            if *patronymic != components.surname {
+                words.extend(patronymic.split_whitespace().map(NamePart::from_name));
+            }
+        }
+
+        let surname_index = words.len();
+        words.extend(
+            components
+                .surname
+                .split_whitespace()
+                .map(NamePart::from_name),
+        );
+
+        let generation_from_suffix = match components.suffix {
+            Some(ref suffix) => {
+                Some(suffix::generation_from_displayed_suffix(suffix).ok_or("unknown suffix")?)
+            }
+            None => None,
+        };
+
+        let titles = Titles {
+            prefix: borrowed(&components.titles),
+            postfix: borrowed(&components.postfix_titles),
+        };
+
+        let name_len = words.iter().map(|w| w.word.len() + 1).sum();
+        let mut name = Name::initialize_struct(
+            &words,
+            surname_index,
+            generation_from_suffix,
+            &titles,
+            name_len,
+        );
+
+        name.nickname = components.nickname.as_ref().map(|n| (**n).into());
+        name.iberian_surnames = components.paternal_surname.is_some();
+        name.initialize_hash();
+
+        Ok(name)
+    }
+}
+
+fn check_component<'a>(text: &'a str) -> Result<Vec<NamePart<'a>>, &'static str> {
+    component_words(text, true, Location::Middle).map_err(|error| match error {
+        ParseError::EmptyComponent => "empty name component",
+        _ => "word is neither a name nor initials",
+    })
+}
+
+// One for each initial, as with any initials we don't know the names for
+fn initial_parts<'a>(initials: &'a str) -> impl Iterator<Item = NamePart<'a>> + 'a {
+    initials
+        .char_indices()
+        .map(move |(i, c)| NamePart::from_initials(&initials[i..i + c.len_utf8()]))
+}
+
+fn borrowed<'a>(titles: &'a [Cow<'a, str>]) -> SmallVec<[Cow<'a, str>; 2]> {
+    titles.iter().map(|title| Cow::Borrowed(&**title)).collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json;
+
+    fn round_trip(name: &str) -> Name {
+        let name = Name::parse(name).unwrap();
+        let json = serde_json::to_string(&name).unwrap();
+        let deserialized: Name = serde_json::from_str(&json).unwrap();
+
+        assert_eq!(name.display_full(), deserialized.display_full());
+        assert_eq!(name.initials(), deserialized.initials());
+        assert_eq!(name.given_name(), deserialized.given_name());
+        assert_eq!(name.middle_names(), deserialized.middle_names());
+        assert_eq!(name.surnames(), deserialized.surnames());
+        assert_eq!(name.hash, deserialized.hash);
+        assert_eq!(json, serde_json::to_string(&deserialized).unwrap());
+        deserialized
+    }
+
+    #[test]
+    fn round_trips() {
+        round_trip("Jane Doe");
+        round_trip("J. Doe");
+        round_trip("J. Mary Doe");
+        round_trip("T Boone Pickens");
+        round_trip("Jean-Paul Sartre");
+        round_trip("John Allen Q. de la MacDonald, Jr.");
+        round_trip("Lt. Col. Jane Doe, MD, PhD");
+        round_trip("Ivan Ivanovich Petrov");
+        round_trip("Björk Guðmundsdóttir");
+        round_trip("Saud bin Abdulaziz bin Abdul Rahman Al Saud");
+        round_trip("William (Bill) Gates III");
+    }
+
+    #[test]
+    fn from_components() {
+        let name = Name::from_components("Mary Ann", &["Jo"], "Smith", None).unwrap();
+        let json = serde_json::to_string(&name).unwrap();
+        let deserialized: Name = serde_json::from_str(&json).unwrap();
+        assert_eq!(Some("Mary Ann"), deserialized.given_name());
+        assert_eq!(name.display_full(), deserialized.display_full());
+        assert_eq!(name.initials(), deserialized.initials());
+    }
+
+    #[test]
+    fn iberian_surnames() {
+        let options = ::ParseOptions {
+            iberian_surnames: true,
+            ..Default::default()
+        };
+        let name = Name::parse_with_options("Juan García Márquez", &options).unwrap();
+        let json = serde_json::to_string(&name).unwrap();
+        let deserialized: Name = serde_json::from_str(&json).unwrap();
+        assert!(name.paternal_surname().is_some());
+        assert_eq!(name.paternal_surname(), deserialized.paternal_surname());
+        assert_eq!(name.hash, deserialized.hash);
+    }
+
+    #[test]
+    fn invalid() {
+        let invalid = [
+            r#"{"first_initial":"J"}"#,
+            r#"{"surname":"","first_initial":"J"}"#,
+            r#"{"surname":"Doe","first_initial":"J","given_name":"Mary"}"#,
+            r#"{"surname":"Doe","first_initial":"J","suffix":"Esq."}"#,
+            r#"{"surname":"Doe","first_initial":"1"}"#,
+            r#"{"surname":"Doe","first_initial":"J","middle_initials":"Q."}"#,
+            r#"{"surname":"Doe","first_initial":"J","given_name":""}"#,
+            r#"{"surname":"Doe","first_initial":"J","given_name":"J%%%ane"}"#,
+            r#"{"surname":"Doe","first_initial":"J","middle_names":" "}"#,
+            r#"{"surname":"D%%%oe","first_initial":"J"}"#,
+            r#"{"surname":" ","first_initial":"J"}"#,
+            r#"{"surname":"D.","first_initial":"J"}"#,
+            r#"{"surname":"Doe","first_initial":"J","patronymic":""}"#,
+            r#"{"surname":"Doe","first_initial":"J","patronymic":"1234"}"#,
+        ];
+        for json in &invalid {
+            assert!(serde_json::from_str::<Name>(json).is_err(), "{}", json);
+        }
+    }
+}
@@ -1,3 +1,4 @@
+use super::utils::join;
 use super::Name;
 use rustc_serialize::json::{Json, ToJson};
 use std::collections::BTreeMap;
@@ -18,6 +19,12 @@ impl ToJson for Name {
     ///   r#"{"first_initial":"J","given_name":"John","middle_initials":"AQ","middle_names":"Allen","suffix":"Jr.","surname":"MacDonald"}"#,
     ///   name.to_json().to_string()
     /// );
+    ///
+    /// let name = Name::parse("Dr. Jane Doe, MD, PhD").unwrap();
+    /// assert_eq!(
+    ///   r#"{"first_initial":"J","given_name":"Jane","postfix_titles":"MD PhD","surname":"Doe","titles":"Dr."}"#,
+    ///   name.to_json().to_string()
+    /// );
     /// # }
     /// ```
     fn to_json(&self) -> Json {
@@ -36,9 +43,30 @@ impl ToJson for Name {
         if let Some(name) = self.middle_name() {
             d.insert("middle_names".to_string(), name.to_json());
         }
+        if let Some(patronymic) = self.patronymic() {
+            d.insert("patronymic".to_string(), patronymic.to_json());
+        }
+        if let Some(surname) = self.paternal_surname() {
+            d.insert("paternal_surname".to_string(), surname.to_json());
+        }
+        if let Some(surname) = self.maternal_surname() {
+            d.insert("maternal_surname".to_string(), surname.to_json());
+        }
         if let Some(suffix) = self.suffix() {
             d.insert("suffix".to_string(), suffix.to_json());
         }
+        if let Some(nickname) = self.nickname() {
+            d.insert("nickname".to_string(), nickname.to_json());
+        }
+        if let Some(titles) = self.titles() {
+            d.insert("titles".to_string(), join(titles.into_iter()).to_json());
+        }
+        if let Some(titles) = self.postfix_titles() {
+            d.insert(
+                "postfix_titles".to_string(),
+                join(titles.into_iter()).to_json(),
+            );
+        }
         Json::Object(d)
     }
 }
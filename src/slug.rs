@@ -0,0 +1,429 @@
+use super::utils::{ascii_letters, is_nonalphanumeric};
+use super::Name;
+use std::collections::HashSet;
+use std::fmt;
+
+// The pieces of a name from which email localparts and URL slugs are built
+#[derive(Clone, Copy, Debug, Eq, PartialEq, Hash)]
+enum SlugPart {
+    GivenName,
+    FirstInitial,
+    MiddleInitials,
+    Surname,
+    SurnameInitials,
+    Separator(char),
+}
+
+use self::SlugPart::*;
+
+// Roughly from most to least common
+static SLUG_PATTERNS: &[&[SlugPart]] = &[
+    &[GivenName, Separator('.'), Surname],
+    &[FirstInitial, Surname],
+    &[GivenName, Surname],
+    &[GivenName, Separator('_'), Surname],
+    &[GivenName, SurnameInitials],
+    &[FirstInitial, Separator('.'), Surname],
+    &[FirstInitial, MiddleInitials, Surname],
+    &[FirstInitial, MiddleInitials, SurnameInitials],
+    &[Surname, Separator('.'), GivenName],
+    &[Surname, FirstInitial],
+    &[GivenName, Separator('-'), Surname],
+    &[Surname, GivenName],
+];
+
+// Conventions we may recognize, but which are too rare (or, alone, too
+// ambiguous) to be worth guessing. A given name or surname alone is never
+// enough for `slug_match`, so we don't offer those at all.
+static RARER_SLUG_PATTERNS: &[&[SlugPart]] = &[
+    &[Surname, FirstInitial, MiddleInitials],
+    &[GivenName, MiddleInitials, Surname],
+    &[GivenName, Separator('.'), SurnameInitials],
+    &[FirstInitial, Separator('_'), Surname],
+    &[FirstInitial, Separator('-'), Surname],
+    &[Surname, Separator('.'), FirstInitial],
+    &[Surname, Separator('_'), GivenName],
+    &[Surname, Separator('_'), FirstInitial],
+];
+
+/// A way of building an email localpart or URL slug from a name, like
+/// `{first}.{last}` for "jane.doe" or `{f}{m}{last}` for "jadoe".
+///
+/// Displays in that form: `{first}` is the given name, `{last}` the surname,
+/// and `{f}`, `{m}` and `{l}` the first, middle and surname initials.
+#[derive(Clone, Copy, Debug, Eq, PartialEq, Hash)]
+pub struct SlugPattern {
+    parts: &'static [SlugPart],
+}
+
+/// The pattern an organization appears to use for its email addresses (see
+/// `infer_slug_convention`), and how confident we are in it, from 0 to 1.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct SlugConvention {
+    pub pattern: SlugPattern,
+    pub confidence: f32,
+}
+
+impl SlugPattern {
+    /// Builds the localpart or slug for a name, transliterated to lowercase
+    /// ASCII, or returns `None` if the name lacks a part the pattern needs
+    /// (like a given name, where we know only its initial), or if the result
+    /// is too short for `slug_match` to trust (like "jli" for "Jane Li").
+    ///
+    /// Middle initials are optional: without them, `{f}{m}{last}` gives the
+    /// same as `{f}{last}`.
+    pub fn apply(&self, name: &Name) -> Option<String> {
+        let mut slug = String::new();
+        for &part in self.parts {
+            match part {
+                GivenName => slug.push_str(&ascii_letters(name.given_name()?)),
+                FirstInitial => slug.push_str(&ascii_letters(&name.first_initial().to_string())),
+                MiddleInitials => {
+                    if let Some(initials) = name.middle_initials() {
+                        slug.push_str(&ascii_letters(initials));
+                    }
+                }
+                Surname => {
+                    for word in name.surname_iter() {
+                        slug.push_str(&ascii_letters(word));
+                    }
+                }
+                SurnameInitials => {
+                    for word in name.surname_iter() {
+                        slug.extend(ascii_letters(word).chars().nth(0));
+                    }
+                }
+                Separator(c) => slug.push(c),
+            }
+        }
+
+        // E.g. a given name with no Latin transliteration
+        if slug.split(is_nonalphanumeric).any(|part| part.is_empty())
+            || name.slug_match(&slug).is_none()
+        {
+            None
+        } else {
+            Some(slug)
+        }
+    }
+
+    fn uses_middle_initials(&self) -> bool {
+        self.parts.contains(&MiddleInitials)
+    }
+}
+
+impl fmt::Display for SlugPattern {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        for &part in self.parts {
+            match part {
+                GivenName => f.write_str("{first}")?,
+                FirstInitial => f.write_str("{f}")?,
+                MiddleInitials => f.write_str("{m}")?,
+                Surname => f.write_str("{last}")?,
+                SurnameInitials => f.write_str("{l}")?,
+                Separator(c) => write!(f, "{}", c)?,
+            }
+        }
+        Ok(())
+    }
+}
+
+impl Name {
+    /// Likely email localparts or URL slugs for this name, such as "jane.doe",
+    /// "jdoe" or "janed", roughly from most to least common.
+    ///
+    /// Names are transliterated to lowercase ASCII, and patterns needing a
+    /// part of the name we don't know (like a middle initial) are skipped,
+    /// as are candidates `matches_slug_or_localpart` wouldn't accept (see
+    /// `SlugPattern::apply`).
+    ///
+    /// ```
+    /// use human_name::Name;
+    /// let name = Name::parse("Jane A. Doe").unwrap();
+    ///
+    /// let candidates: Vec<String> = name.slug_candidates().collect();
+    /// assert_eq!("jane.doe", candidates[0]);
+    /// assert_eq!("jdoe", candidates[1]);
+    /// assert!(candidates.contains(&"janed".to_string()));
+    /// assert!(candidates.contains(&"jad".to_string()));
+    /// assert!(candidates.contains(&"doe.jane".to_string()));
+    /// assert!(candidates.contains(&"jane_doe".to_string()));
+    ///
+    /// for candidate in candidates {
+    ///     assert!(name.matches_slug_or_localpart(&candidate));
+    /// }
+    /// ```
+    pub fn slug_candidates(&self) -> impl Iterator<Item = String> {
+        let has_middle_initials = self.middle_initials().is_some();
+        let mut candidates: Vec<String> = SLUG_PATTERNS
+            .iter()
+            .map(|&parts| SlugPattern { parts })
+            .filter(|pattern| has_middle_initials || !pattern.uses_middle_initials())
+            .filter_map(|pattern| pattern.apply(self))
+            .collect();
+
+        let mut seen = HashSet::with_capacity(candidates.len());
+        candidates.retain(|candidate| seen.insert(candidate.clone()));
+        candidates.into_iter()
+    }
+}
+
+/// Infers the pattern an organization uses to build email localparts from
+/// names (see `SlugPattern`), given the names and localparts of some of its
+/// people, or returns `None` if no known pattern fits any of them.
+///
+/// A pattern's confidence is the share of the pairs it could apply to which
+/// it fits, discounted for small samples: `fits / (applicable + 1)`. Picks
+/// the pattern with the highest confidence, then the one fitting the most
+/// pairs, then the more common pattern.
+/// Localparts are compared case-insensitively, ignoring plus-addressing
+/// ("jdoe+news") and digit suffixes ("jdoe2").
+///
+/// ```
+/// use human_name::{infer_slug_convention, Name};
+///
+/// let known = [
+///     ("Jane A. Doe", "jadoe"),
+///     ("John Smith", "JSmith"),
+///     ("William H. Gates", "whgates"),
+///     ("Mary Smith", "msmith2"),
+/// ];
+/// let names: Vec<Name> = known.iter().map(|&(name, _)| Name::parse(name).unwrap()).collect();
+///
+/// let convention =
+///     infer_slug_convention(names.iter().zip(known.iter().map(|&(_, localpart)| localpart)))
+///         .unwrap();
+/// assert_eq!("{f}{m}{last}", convention.pattern.to_string());
+/// assert_eq!(0.8, convention.confidence);
+///
+/// let name = Name::parse("Steven Paul Jobs").unwrap();
+/// assert_eq!(Some("spjobs".to_string()), convention.pattern.apply(&name));
+/// ```
+pub fn infer_slug_convention<'a, I>(pairs: I) -> Option<SlugConvention>
+where
+    I: IntoIterator<Item = (&'a Name, &'a str)>,
+{
+    let pairs: Vec<(&Name, String)> = pairs
+        .into_iter()
+        .map(|(name, localpart)| (name, normalize_localpart(localpart)))
+        .collect();
+
+    let mut best: Option<(usize, SlugConvention)> = None;
+
+    for &parts in SLUG_PATTERNS.iter().chain(RARER_SLUG_PATTERNS) {
+        let pattern = SlugPattern { parts };
+        let mut applicable = 0;
+        let mut fits = 0;
+
+        for &(name, ref localpart) in &pairs {
+            if let Some(slug) = pattern.apply(name) {
+                applicable += 1;
+                if slug == *localpart {
+                    fits += 1;
+                }
+            }
+        }
+
+        if fits == 0 {
+            continue;
+        }
+
+        let confidence = fits as f32 / (applicable + 1) as f32;
+        let better = match best {
+            Some((best_fits, ref convention)) => {
+                confidence > convention.confidence
+                    || (confidence == convention.confidence && fits > best_fits)
+            }
+            None => true,
+        };
+
+        if better {
+            best = Some((
+                fits,
+                SlugConvention {
+                    pattern,
+                    confidence,
+                },
+            ));
+        }
+    }
+
+    best.map(|(_, convention)| convention)
+}
+
+// Lowercase, without plus-addressing or a digit suffix
+fn normalize_localpart(localpart: &str) -> String {
+    let localpart = localpart.split('+').next().unwrap();
+    localpart
+        .trim_end_matches(|c: char| c.is_ascii_digit())
+        .to_lowercase()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn infer(pairs: &[(&str, &str)]) -> Option<SlugConvention> {
+        let names: Vec<Name> = pairs
+            .iter()
+            .map(|&(name, _)| Name::parse(name).unwrap())
+            .collect();
+        infer_slug_convention(
+            names
+                .iter()
+                .zip(pairs.iter().map(|&(_, localpart)| localpart)),
+        )
+    }
+
+    #[test]
+    fn display() {
+        let patterns: Vec<String> = SLUG_PATTERNS
+            .iter()
+            .take(3)
+            .map(|&parts| SlugPattern { parts }.to_string())
+            .collect();
+        assert_eq!(
+            vec!["{first}.{last}", "{f}{last}", "{first}{last}"],
+            patterns
+        );
+    }
+
+    #[test]
+    fn apply() {
+        let name = Name::parse("José M. del Río").unwrap();
+        let apply = |parts| SlugPattern { parts }.apply(&name);
+        assert_eq!(Some("jose.delrio".to_string()), apply(SLUG_PATTERNS[0]));
+        assert_eq!(Some("josedr".to_string()), apply(SLUG_PATTERNS[4]));
+        assert_eq!(Some("delriojm".to_string()), apply(RARER_SLUG_PATTERNS[0]));
+
+        let name = Name::parse("J. Doe").unwrap();
+        let apply = |parts| SlugPattern { parts }.apply(&name);
+        assert_eq!(None, apply(SLUG_PATTERNS[0]));
+        assert_eq!(Some("jdoe".to_string()), apply(SLUG_PATTERNS[1]));
+        assert_eq!(Some("jdoe".to_string()), apply(SLUG_PATTERNS[6]));
+    }
+
+    #[test]
+    fn candidates_without_middle_initials() {
+        let name = Name::parse("Jane Doe").unwrap();
+        let candidates: Vec<String> = name.slug_candidates().collect();
+        assert!(candidates.contains(&"jdoe".to_string()));
+        assert!(!candidates.contains(&"jd".to_string()));
+    }
+
+    #[test]
+    fn patterns_match() {
+        let names: Vec<Name> = [
+            "Jane A. Doe",
+            "José M. del Río",
+            "William Gates",
+            "Mary Ann Smith",
+        ]
+        .iter()
+        .map(|name| Name::parse(name).unwrap())
+        .collect();
+
+        for &parts in SLUG_PATTERNS.iter().chain(RARER_SLUG_PATTERNS) {
+            let pattern = SlugPattern { parts };
+            let slugs: Vec<(&Name, String)> = names
+                .iter()
+                .filter_map(|name| pattern.apply(name).map(|slug| (name, slug)))
+                .collect();
+            assert!(!slugs.is_empty(), "{} applies to no names", pattern);
+
+            for (name, slug) in slugs {
+                assert!(
+                    name.slug_match(&slug).is_some(),
+                    "{} should match {} ({})",
+                    name.display_full(),
+                    slug,
+                    pattern
+                );
+            }
+        }
+    }
+
+    #[test]
+    fn candidates_with_short_surname() {
+        let name = Name::parse("Jane Li").unwrap();
+        let candidates: Vec<String> = name.slug_candidates().collect();
+        assert!(!candidates.contains(&"jli".to_string()));
+        for candidate in candidates {
+            assert!(name.matches_slug_or_localpart(&candidate));
+        }
+    }
+
+    #[test]
+    fn infer_first_dot_last() {
+        let convention = infer(&[
+            ("Jane Doe", "jane.doe"),
+            ("John Smith", "john.smith"),
+            ("Bill Gates", "william.gates"),
+            ("J. Public", "jpublic"),
+        ])
+        .unwrap();
+        assert_eq!("{first}.{last}", convention.pattern.to_string());
+        assert_eq!(0.5, convention.confidence);
+    }
+
+    #[test]
+    fn infer_last_then_initials() {
+        let convention = infer(&[
+            ("Jane A. Doe", "doeja"),
+            ("John Smith", "smithj"),
+            ("Mary K. Jones", "jonesmk+lists"),
+        ])
+        .unwrap();
+        assert_eq!("{last}{f}{m}", convention.pattern.to_string());
+        assert_eq!(0.75, convention.confidence);
+    }
+
+    #[test]
+    fn infer_with_some_middle_initials() {
+        // `{f}{m}{last}` gives the same as `{f}{last}` for names without
+        // middle initials, but fits worse here
+        let convention = infer(&[
+            ("Jane A. Doe", "jdoe"),
+            ("John Smith", "jsmith"),
+            ("Mary K. Jones", "mjones"),
+            ("Bill Gates", "bgates"),
+        ])
+        .unwrap();
+        assert_eq!("{f}{last}", convention.pattern.to_string());
+        assert_eq!(0.8, convention.confidence);
+
+        let convention = infer(&[
+            ("Jane A. Doe", "jadoe"),
+            ("John Smith", "jsmith"),
+            ("Mary Jones", "mjones"),
+            ("Bill Gates", "bgates"),
+        ])
+        .unwrap();
+        assert_eq!("{f}{m}{last}", convention.pattern.to_string());
+        assert_eq!(0.8, convention.confidence);
+    }
+
+    #[test]
+    fn infer_by_confidence() {
+        // `{f}{last}` fits more pairs, but `{first}.{last}` fits all those
+        // it applies to
+        let convention = infer(&[
+            ("Jane Doe", "jane.doe"),
+            ("John Smith", "john.smith"),
+            ("Mary Jones", "mary.jones"),
+            ("J. Public", "jpublic"),
+            ("K. Lee", "klee"),
+            ("L. Chen", "lchen"),
+            ("M. Brown", "mbrown"),
+        ])
+        .unwrap();
+        assert_eq!("{first}.{last}", convention.pattern.to_string());
+        assert_eq!(0.75, convention.confidence);
+    }
+
+    #[test]
+    fn infer_nothing() {
+        assert!(infer(&[("Jane Doe", "info"), ("John Smith", "sales")]).is_none());
+        assert!(infer(&[]).is_none());
+    }
+}
@@ -0,0 +1,315 @@
+use super::namepart::NamePart;
+use super::nickname;
+use super::parse::{ParseError, ParseOptions, TitleWords};
+use super::utils::{normalize_nfkd_hyphens_spaces_with_changes, Changes};
+use super::Name;
+use std::iter;
+use std::ops::Range;
+
+/// Where each part of a name appears in the string it was parsed from, as
+/// byte ranges of that string (see `Name::try_parse_with_spans`).
+///
+/// Parts are listed in the order they appear in the name, which may not be
+/// their order in the input (as with "Doe, Jane").
+#[derive(Clone, Debug, Default, Eq, PartialEq)]
+pub struct NameSpans {
+    /// Prefix titles, like "Dr."
+    pub titles: Vec<Range<usize>>,
+    /// The first word of the name, whether a given name or an initial (so in
+    /// "T. Boone Pickens", "T.", although the given name is "Boone").
+    pub first_word: Option<Range<usize>>,
+    /// Any other words before the surname: middle names, initials and
+    /// patronymics.
+    pub middle_words: Vec<Range<usize>>,
+    pub surnames: Vec<Range<usize>>,
+    /// The generational suffix, like "Jr." or "III", as written.
+    pub suffix: Option<Range<usize>>,
+    pub postfix_titles: Vec<Range<usize>>,
+    /// The nickname, without its quotes or brackets.
+    pub nickname: Option<Range<usize>>,
+}
+
+impl NameSpans {
+    /// The same spans, counted in characters of the input rather than bytes.
+    ///
+    /// ```
+    /// use human_name::{Name, ParseOptions};
+    ///
+    /// let input = "Søren Kierkegaard";
+    /// let (_, spans) = Name::try_parse_with_spans(input, &ParseOptions::default()).unwrap();
+    /// assert_eq!(vec![7..18], spans.surnames);
+    /// assert_eq!(vec![6..17], spans.in_chars(input).surnames);
+    /// ```
+    pub fn in_chars(&self, input: &str) -> NameSpans {
+        let in_chars = |range: &Range<usize>| {
+            input[..range.start].chars().count()..input[..range.end].chars().count()
+        };
+
+        NameSpans {
+            titles: self.titles.iter().map(&in_chars).collect(),
+            first_word: self.first_word.as_ref().map(&in_chars),
+            middle_words: self.middle_words.iter().map(&in_chars).collect(),
+            surnames: self.surnames.iter().map(&in_chars).collect(),
+            suffix: self.suffix.as_ref().map(&in_chars),
+            postfix_titles: self.postfix_titles.iter().map(&in_chars).collect(),
+            nickname: self.nickname.as_ref().map(&in_chars),
+        }
+    }
+}
+
+impl Name {
+    /// Parses a name as `try_parse_with_options` does, also finding where
+    /// each of its parts appears in the input, as for highlighting them in
+    /// the text the name came from.
+    ///
+    /// Spans are found through the normalization we parse the input with,
+    /// so a part spans whatever the input had, before namecasing or
+    /// decomposing accented characters.
+    ///
+    /// ```
+    /// use human_name::{Name, ParseOptions};
+    ///
+    /// let input = "DR. JOSÉ (PEPE) GARCÍA-MÁRQUEZ, JR., MD";
+    /// let (name, spans) = Name::try_parse_with_spans(input, &ParseOptions::default()).unwrap();
+    /// assert_eq!(Some("Jr."), name.suffix());
+    ///
+    /// let span = |range: &std::ops::Range<usize>| &input[range.clone()];
+    /// assert_eq!(vec!["DR."], spans.titles.iter().map(span).collect::<Vec<_>>());
+    /// assert_eq!(Some("JOSÉ"), spans.first_word.as_ref().map(span));
+    /// assert_eq!(Some("PEPE"), spans.nickname.as_ref().map(span));
+    /// assert_eq!(vec!["GARCÍA-MÁRQUEZ"], spans.surnames.iter().map(span).collect::<Vec<_>>());
+    /// assert_eq!(Some("JR."), spans.suffix.as_ref().map(span));
+    /// assert_eq!(vec!["MD"], spans.postfix_titles.iter().map(span).collect::<Vec<_>>());
+    /// ```
+    pub fn try_parse_with_spans(
+        name: &str,
+        options: &ParseOptions,
+    ) -> Result<(Name, NameSpans), ParseError> {
+        Name::parse_and_locate(name, options, true)
+    }
+}
+
+// Where each of the words a name was parsed from (out of the input with
+// nicknames stripped) appears in the input, by undoing the stripping and
+// then the normalization of the input
+pub fn locate<'a>(
+    input: &str,
+    normalized: &str,
+    stripped: &'a str,
+    words: &[NamePart<'a>],
+    surname_index: usize,
+    title_words: &TitleWords<'a>,
+    nickname: Option<&str>,
+) -> NameSpans {
+    let (_, normalized_changes) = normalize_nfkd_hyphens_spaces_with_changes(input);
+    let (kept, _) = nickname::find_nicknames(normalized);
+    let stripped_changes = removals(&kept, normalized.len());
+
+    // Each word borrows from the text it was parsed from, so we can tell
+    // where in it the word was
+    let span = |word: &str| {
+        range_in(stripped, word)
+            .map(|range| map_range(&stripped_changes, range))
+            .map(|range| map_range(&normalized_changes, range))
+    };
+
+    NameSpans {
+        titles: title_words.prefix.iter().filter_map(|w| span(w)).collect(),
+        first_word: span(words[0].word),
+        middle_words: words[1..surname_index]
+            .iter()
+            .filter_map(|w| span(w.word))
+            .collect(),
+        surnames: words[surname_index..]
+            .iter()
+            .filter_map(|w| span(w.word))
+            .collect(),
+        suffix: title_words.suffix.and_then(&span),
+        postfix_titles: title_words.postfix.iter().filter_map(|w| span(w)).collect(),
+        nickname: nickname
+            .and_then(|n| range_in(normalized, n))
+            .map(|range| map_range(&normalized_changes, range)),
+    }
+}
+
+// Where a slice of some text is within it
+fn range_in(text: &str, slice: &str) -> Option<Range<usize>> {
+    let start = (slice.as_ptr() as usize).checked_sub(text.as_ptr() as usize)?;
+    if start + slice.len() <= text.len() {
+        Some(start..start + slice.len())
+    } else {
+        None
+    }
+}
+
+// Each stretch of the text between the kept ranges, as an empty range of the
+// text they make up, paired with the range of the text it replaced
+fn removals(kept: &[Range<usize>], len: usize) -> Changes {
+    let mut changes = Vec::new();
+    let mut kept_len = 0;
+    let mut prior_end = 0;
+
+    for range in kept.iter().chain(iter::once(&(len..len))) {
+        if range.start > prior_end {
+            changes.push((kept_len..kept_len, prior_end..range.start));
+        }
+        kept_len += range.end - range.start;
+        prior_end = range.end;
+    }
+
+    changes
+}
+
+// Maps a range of text derived from some input back to the input, given
+// the (ordered, non-overlapping) ranges of the text which differ from it and
+// the ranges of the input they replaced. Text outside those ranges was
+// copied from the input as is, so maps byte for byte; a range starting or
+// ending within a replacement spans all of what it replaced.
+fn map_range(changes: &[(Range<usize>, Range<usize>)], range: Range<usize>) -> Range<usize> {
+    map_offset(changes, range.start, false)..map_offset(changes, range.end, true)
+}
+
+fn map_offset(changes: &[(Range<usize>, Range<usize>)], offset: usize, is_end: bool) -> usize {
+    let mut shift_from = (0, 0);
+
+    for (text, input) in changes {
+        if is_end && text.start < offset && offset <= text.end {
+            return input.end;
+        } else if !is_end && text.start <= offset && offset < text.end {
+            return input.start;
+        } else if text.start > offset || (is_end && text.start == offset) {
+            break;
+        }
+        shift_from = (text.end, input.end);
+    }
+
+    shift_from.1 + offset - shift_from.0
+}
+
+#[cfg(test)]
+mod tests {
+    use super::super::MAX_NAME_LEN;
+    use super::*;
+
+    fn parse_spans(input: &str) -> (Name, NameSpans) {
+        Name::try_parse_with_spans(input, &ParseOptions::default()).unwrap()
+    }
+
+    fn text<'a>(input: &'a str, ranges: &[Range<usize>]) -> Vec<&'a str> {
+        ranges.iter().map(|range| &input[range.clone()]).collect()
+    }
+
+    #[test]
+    fn simple() {
+        let (name, spans) = parse_spans("Jane Doe");
+        assert_eq!("Jane Doe", name.display_full());
+        assert_eq!(Some(0..4), spans.first_word);
+        assert!(spans.middle_words.is_empty());
+        assert_eq!(vec![5..8], spans.surnames);
+        assert_eq!(None, spans.suffix);
+        assert_eq!(None, spans.nickname);
+    }
+
+    #[test]
+    fn same_name_as_parse() {
+        let inputs = [
+            "Jane Doe",
+            "  JOHN   ALLEN Q DE LA MACDONALD JR",
+            "Doe, Jane A.",
+            "Ｊａｎｅ\u{3000}Ｄｏｅ",
+            "William “Bill” Gates, III",
+            "Robert 'Bob' (Bobby) Roberts",
+            "Dr. Martin Luther King, Jr., PhD",
+        ];
+        for input in &inputs {
+            let (name, _) = parse_spans(input);
+            let parsed = Name::parse(input).unwrap();
+            assert_eq!(parsed.display_full(), name.display_full(), "{}", input);
+            assert_eq!(parsed.hash, name.hash, "{}", input);
+        }
+    }
+
+    #[test]
+    fn words() {
+        let input = "  JOHN   ALLEN Q DE LA MACDONALD JR";
+        let (_, spans) = parse_spans(input);
+        assert_eq!(Some("JOHN"), spans.first_word.map(|r| &input[r]));
+        assert_eq!(vec!["ALLEN", "Q"], text(input, &spans.middle_words));
+        assert_eq!(vec!["DE", "LA", "MACDONALD"], text(input, &spans.surnames));
+        assert_eq!(Some("JR"), spans.suffix.map(|r| &input[r]));
+    }
+
+    #[test]
+    fn surname_first() {
+        let input = "Doe, Jane A.";
+        let (_, spans) = parse_spans(input);
+        assert_eq!(Some(5..9), spans.first_word);
+        assert_eq!(vec!["A."], text(input, &spans.middle_words));
+        assert_eq!(vec!["Doe"], text(input, &spans.surnames));
+    }
+
+    #[test]
+    fn titles() {
+        let input = "Dr. Martin Luther King, Jr., PhD";
+        let (_, spans) = parse_spans(input);
+        assert_eq!(vec!["Dr."], text(input, &spans.titles));
+        assert_eq!(vec!["Luther"], text(input, &spans.middle_words));
+        assert_eq!(Some("Jr."), spans.suffix.map(|r| &input[r]));
+        assert_eq!(vec!["PhD"], text(input, &spans.postfix_titles));
+    }
+
+    #[test]
+    fn normalized() {
+        // Fullwidth characters decompose, and the ideographic space is
+        // replaced, so every byte of the input changes length
+        let input = "Ｊａｎｅ\u{3000}Ｄｏｅ";
+        let (_, spans) = parse_spans(input);
+        assert_eq!(Some("Ｊａｎｅ"), spans.first_word.map(|r| &input[r]));
+        assert_eq!(vec!["Ｄｏｅ"], text(input, &spans.surnames));
+
+        let input = "Zoë Saldaña‐Nazario";
+        let (_, spans) = parse_spans(input);
+        assert_eq!(Some("Zoë"), spans.first_word.map(|r| &input[r]));
+        assert_eq!(vec!["Saldaña‐Nazario"], text(input, &spans.surnames));
+    }
+
+    #[test]
+    fn nicknames() {
+        let input = "William “Bill” Gates, III";
+        let (_, spans) = parse_spans(input);
+        assert_eq!(Some("Bill"), spans.nickname.map(|r| &input[r]));
+        assert_eq!(vec!["Gates"], text(input, &spans.surnames));
+        assert_eq!(Some("III"), spans.suffix.map(|r| &input[r]));
+
+        // Later nicknames are stripped, but not captured
+        let input = "Robert 'Bob' (Bobby) Roberts";
+        let (_, spans) = parse_spans(input);
+        assert_eq!(Some("Bob"), spans.nickname.map(|r| &input[r]));
+        assert_eq!(vec!["Roberts"], text(input, &spans.surnames));
+    }
+
+    #[test]
+    fn map_ranges() {
+        // "aXYb" from "aZb", then "cdgh" kept from "abcdefgh"
+        let changes = [(1..3, 1..2)];
+        assert_eq!(0..1, map_range(&changes, 0..1));
+        assert_eq!(1..2, map_range(&changes, 1..2));
+        assert_eq!(1..3, map_range(&changes, 1..4));
+        assert_eq!(2..3, map_range(&changes, 3..4));
+
+        let changes = removals(&[2..4, 6..8], 8);
+        assert_eq!(vec![(0..0, 0..2), (2..2, 4..6)], changes);
+        assert_eq!(2..4, map_range(&changes, 0..2));
+        assert_eq!(6..8, map_range(&changes, 2..4));
+        assert_eq!(2..8, map_range(&changes, 0..4));
+    }
+
+    #[test]
+    fn too_long() {
+        let input = "Jane Doe ".repeat(MAX_NAME_LEN / 8);
+        assert_eq!(
+            Err(ParseError::TooLong),
+            Name::try_parse_with_spans(&input, &ParseOptions::default()).map(|_| ())
+        );
+    }
+}
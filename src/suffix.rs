@@ -93,6 +93,14 @@ pub fn display_generational_suffix(generation: u8) -> &'static str {
     SUFFIX_BY_GENERATION[generation as usize - 1]
 }
 
+#[cfg(feature = "serde")]
+pub fn generation_from_displayed_suffix(suffix: &str) -> Option<u8> {
+    SUFFIX_BY_GENERATION
+        .iter()
+        .position(|s| *s == suffix)
+        .map(|i| i as u8 + 1)
+}
+
 #[cfg(test)]
 mod tests {
     use super::super::namepart::{Location, NamePart};
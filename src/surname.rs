@@ -1,4 +1,5 @@
 use super::namepart::{Category, NamePart};
+use super::patronymic::is_patronymic_particle;
 use phf;
 
 static VOWELLESS_SURNAMES: [&'static str; 4] = ["Ng", "Lv", "Mtz", "Hdz"];
@@ -90,17 +91,27 @@ pub fn is_vowelless_surname(word: &str, use_capitalization: bool) -> bool {
     }
 }
 
-pub fn find_surname_index(words: &[NamePart]) -> usize {
+pub fn is_surname_prefix(word: &NamePart) -> bool {
+    let key: &str = match word.category {
+        Category::Name(ref namecased) => &*namecased,
+        _ => word.word,
+    };
+    SURNAME_PREFIXES.contains(key)
+}
+
+pub fn find_surname_index(words: &[NamePart], arabic_patronymics: bool) -> usize {
     if words.len() < 2 {
         return 0;
     }
 
     for (i, word) in words[0..words.len() - 1].iter().enumerate() {
-        let key: &str = match word.category {
-            Category::Name(ref namecased) => &*namecased,
-            _ => word.word,
-        };
-        if SURNAME_PREFIXES.contains(key) {
+        // A patronymic like "bin Salman" is only the surname if nothing follows
+        // it (as in "Mohammed bin Salman", but not "Mohammed bin Salman Al Saud")
+        if arabic_patronymics && i + 2 < words.len() && is_patronymic_particle(word.word) {
+            continue;
+        }
+
+        if is_surname_prefix(word) {
             return i;
         }
 
@@ -118,6 +129,79 @@ pub fn find_surname_index(words: &[NamePart]) -> usize {
     words.len() - 1
 }
 
+// Like `find_surname_index`, but for names expected to end with both a
+// paternal and a maternal surname (e.g. "Juan Pérez González"), each of which
+// may have its own particles (e.g. "Juan Pérez de la Cruz")
+pub fn find_iberian_surname_index(words: &[NamePart], arabic_patronymics: bool) -> usize {
+    if words.len() < 2 {
+        return 0;
+    }
+
+    let start = find_surname_index(words, arabic_patronymics);
+
+    // A conjunction means we already have both surnames
+    if words[start + 1..]
+        .iter()
+        .any(|word| SINGLE_LETTER_CONJUNCTIONS.contains(&word.word))
+    {
+        return start;
+    }
+
+    // Otherwise we've only found the maternal surname, so the previous word
+    // and any particles before it make up the paternal surname
+    let mut start = start;
+    if start > 0 && words[start - 1].is_namelike() && !is_surname_prefix(&words[start - 1]) {
+        start -= 1;
+        while start > 0 && is_surname_prefix(&words[start - 1]) {
+            start -= 1;
+        }
+    }
+    start
+}
+
+// Splits the words of a parsed surname into paternal and maternal surnames,
+// returning the number of words in the paternal surname and the index of the
+// first word of the maternal surname (these differ by one when the two are
+// joined by a conjunction, as in "Velasquez y Garcia")
+pub fn split_iberian_surname(words: &[&str]) -> Option<(usize, usize)> {
+    if words.len() < 2 {
+        return None;
+    }
+
+    if let Some(i) = words[1..words.len() - 1]
+        .iter()
+        .position(|word| SINGLE_LETTER_CONJUNCTIONS.contains(word))
+    {
+        return Some((i + 1, i + 2));
+    }
+
+    let mut start = words.len() - 1;
+    while start > 0 && SURNAME_PREFIXES.contains(words[start - 1]) {
+        start -= 1;
+    }
+
+    if start > 0 {
+        Some((start, start))
+    } else {
+        None
+    }
+}
+
+// For names written surname-first, the number of leading words making up the
+// surname: normally one, plus any particles (e.g. "de Orbán Viktor"), but
+// always leaving at least one word for the given name
+pub fn find_leading_surname_len(words: &[NamePart]) -> usize {
+    if words.len() < 2 {
+        return words.len();
+    }
+
+    words[0..words.len() - 2]
+        .iter()
+        .take_while(|word| is_surname_prefix(word))
+        .count()
+        + 1
+}
+
 #[cfg(test)]
 mod tests {
     use super::super::namepart::{Location, NamePart};
@@ -126,67 +210,131 @@ mod tests {
     #[test]
     fn one_word() {
         let parts: Vec<_> = NamePart::all_from_text("Doe", true, Location::Start).collect();
-        assert_eq!(0, find_surname_index(&*parts));
+        assert_eq!(0, find_surname_index(&*parts, false));
     }
 
     #[test]
     fn two_words() {
         let parts: Vec<_> = NamePart::all_from_text("Jane Doe", true, Location::Start).collect();
-        assert_eq!(1, find_surname_index(&*parts));
+        assert_eq!(1, find_surname_index(&*parts, false));
     }
 
     #[test]
     fn three_words() {
         let parts: Vec<_> =
             NamePart::all_from_text("Jane Emily Doe", true, Location::Start).collect();
-        assert_eq!(2, find_surname_index(&*parts));
+        assert_eq!(2, find_surname_index(&*parts, false));
     }
 
     #[test]
     fn conjunction_after_nothing() {
         let parts: Vec<_> = NamePart::all_from_text("y Velazquez", true, Location::Start).collect();
-        assert_eq!(1, find_surname_index(&*parts));
+        assert_eq!(1, find_surname_index(&*parts, false));
     }
 
     #[test]
     fn conjunction_after_one() {
         let parts: Vec<_> =
             NamePart::all_from_text("Rodrigo y Velazquez", true, Location::Start).collect();
-        assert_eq!(0, find_surname_index(&*parts));
+        assert_eq!(0, find_surname_index(&*parts, false));
     }
 
     #[test]
     fn conjunction_after_two() {
         let parts: Vec<_> =
             NamePart::all_from_text("Jane Rodrigo y Velazquez", true, Location::Start).collect();
-        assert_eq!(1, find_surname_index(&*parts));
+        assert_eq!(1, find_surname_index(&*parts, false));
     }
 
     #[test]
     fn particle_after_nothing() {
         let parts: Vec<_> =
             NamePart::all_from_text("Abd al-Qader", true, Location::Start).collect();
-        assert_eq!(0, find_surname_index(&*parts));
+        assert_eq!(0, find_surname_index(&*parts, false));
     }
 
     #[test]
     fn particle_after_one() {
         let parts: Vec<_> =
             NamePart::all_from_text("Jane Abd al-Qader", true, Location::Start).collect();
-        assert_eq!(1, find_surname_index(&*parts));
+        assert_eq!(1, find_surname_index(&*parts, false));
     }
 
     #[test]
     fn particle_and_conjunction() {
         let parts: Vec<_> =
             NamePart::all_from_text("Alejandro de Aza y Cabra", true, Location::Start).collect();
-        assert_eq!(1, find_surname_index(&*parts));
+        assert_eq!(1, find_surname_index(&*parts, false));
+    }
+
+    #[test]
+    fn patronymic_particle() {
+        let parts: Vec<_> =
+            NamePart::all_from_text("Mohammed bin Salman", true, Location::Start).collect();
+        assert_eq!(1, find_surname_index(&*parts, false));
+
+        let parts: Vec<_> =
+            NamePart::all_from_text("Mohammed bin Salman Al Saud", true, Location::Start).collect();
+        assert_eq!(1, find_surname_index(&*parts, false));
+        assert_eq!(3, find_surname_index(&*parts, true));
+    }
+
+    #[test]
+    fn leading_surname() {
+        let parts: Vec<_> = NamePart::all_from_text("Kim Il-sung", true, Location::Start).collect();
+        assert_eq!(1, find_leading_surname_len(&*parts));
+
+        let parts: Vec<_> =
+            NamePart::all_from_text("Nguyen Van An", true, Location::Start).collect();
+        assert_eq!(1, find_leading_surname_len(&*parts));
+
+        let parts: Vec<_> =
+            NamePart::all_from_text("de Orbán Viktor", true, Location::Start).collect();
+        assert_eq!(2, find_leading_surname_len(&*parts));
+
+        let parts: Vec<_> = NamePart::all_from_text("de Orbán", true, Location::Start).collect();
+        assert_eq!(1, find_leading_surname_len(&*parts));
+    }
+
+    #[test]
+    fn iberian_surnames() {
+        let parts: Vec<_> =
+            NamePart::all_from_text("Pérez González", true, Location::Start).collect();
+        assert_eq!(0, find_iberian_surname_index(&*parts, false));
+
+        let parts: Vec<_> =
+            NamePart::all_from_text("Carlos Pérez González", true, Location::Start).collect();
+        assert_eq!(1, find_iberian_surname_index(&*parts, false));
+
+        let parts: Vec<_> =
+            NamePart::all_from_text("Pérez de la Cruz", true, Location::Start).collect();
+        assert_eq!(0, find_iberian_surname_index(&*parts, false));
+
+        let parts: Vec<_> =
+            NamePart::all_from_text("Alberto T. Velasquez y Garcia", true, Location::Start)
+                .collect();
+        assert_eq!(2, find_iberian_surname_index(&*parts, false));
+
+        let parts: Vec<_> = NamePart::all_from_text("A. Pérez", true, Location::Start).collect();
+        assert_eq!(1, find_iberian_surname_index(&*parts, false));
+
+        assert_eq!(Some((1, 1)), split_iberian_surname(&["Pérez", "González"]));
+        assert_eq!(
+            Some((1, 1)),
+            split_iberian_surname(&["Pérez", "de", "la", "Cruz"])
+        );
+        assert_eq!(
+            Some((1, 2)),
+            split_iberian_surname(&["Velasquez", "y", "Garcia"])
+        );
+        assert_eq!(None, split_iberian_surname(&["de", "la", "Cruz"]));
+        assert_eq!(None, split_iberian_surname(&["Pérez"]));
     }
 
     #[test]
     fn conjunction_and_particle() {
         let parts: Vec<_> =
             NamePart::all_from_text("Alejandro Cabra y de Aza", true, Location::Start).collect();
-        assert_eq!(1, find_surname_index(&*parts));
+        assert_eq!(1, find_surname_index(&*parts, false));
     }
 }
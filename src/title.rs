@@ -5,6 +5,10 @@ use std::cmp;
 
 static TWO_CHAR_TITLES: [&'static str; 4] = ["mr", "ms", "sr", "dr"];
 
+// Postfixes which say the name is part of a list, rather than describing
+// the person
+static LIST_POSTFIXES: [&'static str; 2] = ["et", "al"];
+
 static PREFIX_TITLE_PARTS: phf::Set<&'static str> = phf_set! {
     "Aunt",
     "Auntie",
@@ -432,7 +436,7 @@ fn might_be_last_title_part(word: &NamePart) -> bool {
     }
 }
 
-fn is_prefix_title(words: &[NamePart]) -> bool {
+pub fn is_prefix_title(words: &[NamePart]) -> bool {
     match words.last() {
         Some(word) => {
             if !might_be_last_title_part(&word) {
@@ -453,7 +457,7 @@ fn is_prefix_title(words: &[NamePart]) -> bool {
     }
 }
 
-fn is_postfix_title(word: &NamePart, might_be_initials: bool) -> bool {
+pub fn is_postfix_title(word: &NamePart, might_be_initials: bool) -> bool {
     match word.category {
         Category::Name(ref namecased) => {
             let namecased: &str = &*namecased;
@@ -464,6 +468,13 @@ fn is_postfix_title(word: &NamePart, might_be_initials: bool) -> bool {
     }
 }
 
+pub fn is_list_postfix(word: &NamePart) -> bool {
+    let word = word.word.trim_end_matches('.');
+    LIST_POSTFIXES
+        .iter()
+        .any(|postfix| postfix.eq_ignore_ascii_case(word))
+}
+
 pub fn find_prefix_len(words: &[NamePart]) -> usize {
     let mut prefix_len = words.len() - 1;
 
@@ -536,6 +547,16 @@ mod tests {
         assert!(!is_postfix_title(&part, true));
     }
 
+    #[test]
+    fn is_list_postfix_et_al() {
+        let parts: Vec<_> = NamePart::all_from_text("et al.", true, Location::Start).collect();
+        for part in parts {
+            assert!(is_list_postfix(&part));
+        }
+        let part = NamePart::from_word("esq", true, Location::Start);
+        assert!(!is_list_postfix(&part));
+    }
+
     #[test]
     fn find_prefix_len_none() {
         let parts: Vec<_> = NamePart::all_from_text("Jane Doe", true, Location::Start).collect();
@@ -1,4 +1,6 @@
 use std::borrow::Cow;
+use std::iter;
+use std::ops::Range;
 use std::str::Chars;
 use unicode_normalization::char::{canonical_combining_class, decompose_compatible};
 use unicode_normalization::UnicodeNormalization;
@@ -38,6 +40,79 @@ pub fn is_mixed_case(s: &str) -> bool {
     }
 }
 
+// Scripts in which names are conventionally written surname-first: Han,
+// Hangul (precomposed or as NFKD jamo), and Japanese kana
+#[inline]
+pub fn is_cjk(c: char) -> bool {
+    match c {
+        '\u{1100}'...'\u{11FF}' => true,
+        '\u{3040}'...'\u{30FF}' => true,
+        '\u{3130}'...'\u{318F}' => true,
+        '\u{3400}'...'\u{4DBF}' => true,
+        '\u{4E00}'...'\u{9FFF}' => true,
+        '\u{AC00}'...'\u{D7AF}' => true,
+        '\u{F900}'...'\u{FAFF}' => true,
+        '\u{20000}'...'\u{2FA1F}' => true,
+        _ => false,
+    }
+}
+
+pub fn is_cjk_text(s: &str) -> bool {
+    let mut alphabetic = s.chars().filter(|c| c.is_alphabetic()).peekable();
+    alphabetic.peek().is_some() && alphabetic.all(is_cjk)
+}
+
+#[inline]
+pub fn is_hangul_jamo(c: char) -> bool {
+    match c {
+        '\u{1100}'...'\u{11FF}' => true,
+        _ => false,
+    }
+}
+
+#[inline]
+fn is_hangul_leading_jamo(c: char) -> bool {
+    match c {
+        '\u{1100}'...'\u{115F}' => true,
+        _ => false,
+    }
+}
+
+#[inline]
+fn is_hangul_syllable(c: char) -> bool {
+    match c {
+        '\u{AC00}'...'\u{D7A3}' => true,
+        _ => false,
+    }
+}
+
+// Korean names are often written without a space between the (almost always
+// single-syllable) surname and given name, so we split off the first syllable,
+// as long as the word looks like a name of two to four syllables.
+//
+// Handles both precomposed syllables and the NFKD jamo we normalize them to.
+pub fn split_first_hangul_syllable(word: &str) -> Option<(&str, &str)> {
+    let syllable_starts: SmallVec<[usize; 4]> = if word.chars().all(is_hangul_syllable) {
+        word.char_indices().map(|(i, _)| i).take(5).collect()
+    } else if word.chars().nth(0).map(is_hangul_leading_jamo) == Some(true)
+        && word.chars().all(is_hangul_jamo)
+    {
+        word.char_indices()
+            .filter(|&(_, c)| is_hangul_leading_jamo(c))
+            .map(|(i, _)| i)
+            .take(5)
+            .collect()
+    } else {
+        return None;
+    };
+
+    if syllable_starts.len() >= 2 && syllable_starts.len() <= 4 {
+        Some(word.split_at(syllable_starts[1]))
+    } else {
+        None
+    }
+}
+
 #[inline]
 pub fn is_combining(c: char) -> bool {
     canonical_combining_class(c) > 0
@@ -88,9 +163,10 @@ pub fn transliterate(c: char) -> Chars<'static> {
 
 #[inline]
 pub fn to_ascii_letter(c: char) -> Option<char> {
-    debug_assert!(c.is_uppercase(), c.to_string());
     match c {
         'A'...'Z' => Some(c),
+        // E.g. the initial of a particle like "de" among given names
+        'a'...'z' => Some(c.to_ascii_uppercase()),
         _ => transliterate(c)
             .next()
             .and_then(|c| c.to_uppercase().next()),
@@ -128,6 +204,15 @@ pub fn to_ascii(s: &str) -> Cow<str> {
     }
 }
 
+// Lowercase, transliterated, and with anything but letters dropped
+pub fn ascii_letters(s: &str) -> String {
+    s.chars()
+        .flat_map(transliterate)
+        .filter_map(lowercase_if_alpha)
+        .filter(|c| c.is_ascii())
+        .collect()
+}
+
 pub fn capitalize_word(word: &str, simple: bool) -> String {
     debug_assert!(simple == word.chars().all(is_ascii_alphabetic));
 
@@ -183,23 +268,73 @@ pub fn normalize_nfkd_hyphens_spaces(string: &str) -> Cow<str> {
     {
         Cow::Borrowed(string)
     } else {
-        let string = string
-            .nfkd()
-            .map(|c| {
-                if HYPHENS.contains(c) {
-                    '-'
-                } else if c.is_whitespace() {
-                    ' '
-                } else {
-                    c
-                }
-            })
-            .collect();
+        let string = string.nfkd().map(normalize_hyphen_or_space).collect();
 
         Cow::Owned(string)
     }
 }
 
+// The ranges of some text which differ from the input it was derived from,
+// each paired with the range of the input it replaced
+pub type Changes = Vec<(Range<usize>, Range<usize>)>;
+
+// As `normalize_nfkd_hyphens_spaces`, along with the changes made, so that
+// offsets in the output can be mapped back to the input
+pub fn normalize_nfkd_hyphens_spaces_with_changes<'a>(string: &'a str) -> (Cow<'a, str>, Changes) {
+    let normalized = normalize_nfkd_hyphens_spaces(string);
+    if let Cow::Borrowed(_) = normalized {
+        return (normalized, Vec::new());
+    }
+
+    // Decomposition only reorders combining marks, so the input can be
+    // normalized a piece at a time, each starting where a character
+    // decomposes to a starter
+    let mut output = String::with_capacity(normalized.len());
+    let mut changes = Vec::new();
+    let mut piece_start = 0;
+
+    let boundaries = string
+        .char_indices()
+        .skip(1)
+        .filter(|&(_, c)| decomposes_to_starter(c))
+        .map(|(i, _)| i)
+        .chain(iter::once(string.len()));
+
+    for piece_end in boundaries {
+        let piece = &string[piece_start..piece_end];
+        let prior_len = output.len();
+        output.extend(piece.nfkd().map(normalize_hyphen_or_space));
+
+        if output[prior_len..] != *piece {
+            changes.push((prior_len..output.len(), piece_start..piece_end));
+        }
+        piece_start = piece_end;
+    }
+
+    debug_assert_eq!(normalized, output);
+    (Cow::Owned(output), changes)
+}
+
+fn normalize_hyphen_or_space(c: char) -> char {
+    if HYPHENS.contains(c) {
+        '-'
+    } else if c.is_whitespace() {
+        ' '
+    } else {
+        c
+    }
+}
+
+fn decomposes_to_starter(c: char) -> bool {
+    let mut first = None;
+    decompose_compatible(c, |d| {
+        if first.is_none() {
+            first = Some(d)
+        }
+    });
+    first.map_or(true, |d| !is_combining(d))
+}
+
 #[derive(Debug, Clone)]
 pub struct CharacterCounts {
     pub chars: u8,
@@ -367,6 +502,34 @@ mod tests {
         assert!(!has_sequential_alphas("ﾟ."));
     }
 
+    #[test]
+    fn ascii_initials() {
+        assert_eq!(Some('J'), to_ascii_letter('J'));
+        assert_eq!(Some('D'), to_ascii_letter('d'));
+        assert_eq!(Some('E'), to_ascii_letter('É'));
+    }
+
+    #[test]
+    fn cjk_text() {
+        assert!(is_cjk_text("鄭和"));
+        assert!(is_cjk_text("이용희"));
+        assert!(is_cjk_text("山田 太郎"));
+        assert!(!is_cjk_text("Zheng He"));
+        assert!(!is_cjk_text("鈴木 Velasquez"));
+        assert!(!is_cjk_text("..."));
+    }
+
+    #[test]
+    fn hangul_syllables() {
+        assert_eq!(Some(("이", "용희")), split_first_hangul_syllable("이용희"));
+        let nfkd: String = "이용희".nfkd().collect();
+        let (surname, given_name) = split_first_hangul_syllable(&nfkd).unwrap();
+        assert_eq!("이", surname.nfc().collect::<String>());
+        assert_eq!("용희", given_name.nfc().collect::<String>());
+        assert_eq!(None, split_first_hangul_syllable("이"));
+        assert_eq!(None, split_first_hangul_syllable("鄭和"));
+    }
+
     #[test]
     fn capitalization() {
         assert_eq!("A", capitalize_word("a", true));
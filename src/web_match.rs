@@ -1,11 +1,38 @@
 use super::utils::*;
 use super::Name;
+use phf;
 use std::borrow::Cow;
+use std::ops::Range;
+
+/// How a string matched a name in `slug_match`, from the most to the least
+/// trustworthy.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub enum SlugMatch {
+    /// Punctuation let us parse the string as a name consistent with this
+    /// one, as with "jane.doe"
+    Parsed,
+    /// The string is all of the name's initials, as with "jad"
+    FullInitials,
+    /// The string is the given name plus the surname's initials, as with
+    /// "janed"
+    GivenNameAndSurnameInitials,
+    /// We found the surname, or at least its first few letters, in the
+    /// string (as with "janedoe" or "jdoe"), and the rest matched the
+    /// remaining parts of the name
+    Surname {
+        /// The bytes of the input string where we found the surname
+        span: Range<usize>,
+        /// Whether we found the whole surname, or only its first few letters
+        exact: bool,
+    },
+}
 
 impl Name {
     /// Does this name appear to match a munged string such as an email
     /// localpart or URL slug, where whitespace has been removed?
     ///
+    /// See `slug_match` to learn how it matched.
+    ///
     /// # Examples
     ///
     /// ```
@@ -27,8 +54,31 @@ impl Name {
     ///
     /// ```
     pub fn matches_slug_or_localpart(&self, string: &str) -> bool {
+        self.slug_match(string).is_some()
+    }
+
+    /// Like `matches_slug_or_localpart`, but describes how the string
+    /// matched, if it did.
+    ///
+    /// ```
+    /// use human_name::{Name, SlugMatch};
+    /// let name = Name::parse("Jane A. Doe").unwrap();
+    ///
+    /// assert_eq!(Some(SlugMatch::Parsed), name.slug_match("jane.doe"));
+    /// assert_eq!(Some(SlugMatch::FullInitials), name.slug_match("jad"));
+    /// assert_eq!(
+    ///     Some(SlugMatch::GivenNameAndSurnameInitials),
+    ///     name.slug_match("janed")
+    /// );
+    /// assert_eq!(
+    ///     Some(SlugMatch::Surname { span: 4..7, exact: true }),
+    ///     name.slug_match("janedoe")
+    /// );
+    /// assert_eq!(None, name.slug_match("john.doe"));
+    /// ```
+    pub fn slug_match(&self, string: &str) -> Option<SlugMatch> {
         if string.is_empty() {
-            return false;
+            return None;
         }
 
         // Special case: Nice punctuation lets us actually parse a name directly
@@ -37,7 +87,7 @@ impl Name {
 
             if let Some(name) = Name::parse(&subbed) {
                 if name.consistent_with(self) {
-                    return true;
+                    return Some(SlugMatch::Parsed);
                 }
             }
         }
@@ -57,7 +107,7 @@ impl Name {
         };
 
         if normed.is_empty() {
-            return false;
+            return None;
         }
 
         // Special case: Full initials
@@ -72,24 +122,26 @@ impl Name {
             );
 
             if *normed == initials {
-                return true;
+                return Some(SlugMatch::FullInitials);
             }
         }
 
-        // Special case: Given name plus surname initial
+        // Special case: Given name plus surname initial (ignoring hyphens and
+        // accents in the given name, as in "Chia-Cheng" or "Raúl")
         if let Some(name) = self.given_name() {
-            let name_and_initial_len = name.len() + self.surname_words();
-            if normed.len() == name_and_initial_len {
-                let mut name_and_initial = String::with_capacity(name_and_initial_len);
-                name_and_initial.extend(name.chars().flat_map(char::to_lowercase));
-                name_and_initial.extend(
-                    self.surname_iter()
-                        .filter_map(|n| n.chars().nth(0))
-                        .flat_map(char::to_lowercase),
-                );
-
-                if *normed == name_and_initial {
-                    return true;
+            if normed.len() <= name.len() + self.surname_words() {
+                let name_and_initial: String = name
+                    .chars()
+                    .filter_map(lowercase_if_alpha)
+                    .chain(
+                        self.surname_iter()
+                            .filter_map(|n| n.chars().nth(0))
+                            .flat_map(char::to_lowercase),
+                    )
+                    .collect();
+
+                if *normed == name_and_initial || *normed == ascii_letters(&name_and_initial) {
+                    return Some(SlugMatch::GivenNameAndSurnameInitials);
                 }
             }
         }
@@ -101,7 +153,7 @@ impl Name {
         // rest of the name.
         let search_result = self.find_surname_in(&normed);
         if search_result.is_none() {
-            return false;
+            return None;
         }
 
         let (match_begin, match_len, found_exact_surname) = search_result.unwrap();
@@ -121,20 +173,58 @@ impl Name {
         if prefix.map(|s| s.len()).unwrap_or(0) < 2 && suffix.map(|s| s.len()).unwrap_or(0) < 2 {
             // Don't allow just a two-letter surname match to result in an overall match
             if match_len < 3 {
-                return false;
+                return None;
             }
 
             // Don't allow just a 3 or 4-char part-surname match to result in an overall match
             if match_len < 5 && !found_exact_surname {
-                return false;
+                return None;
             }
         }
 
         let allow_unknowns = found_exact_surname && (prefix.is_none() || suffix.is_none());
 
-        (prefix.is_none() || self.matches_remaining_name_parts(prefix.unwrap(), allow_unknowns))
+        if (prefix.is_none() || self.matches_remaining_name_parts(prefix.unwrap(), allow_unknowns))
             && (suffix.is_none()
                 || self.matches_remaining_name_parts(suffix.unwrap(), allow_unknowns))
+        {
+            Some(SlugMatch::Surname {
+                span: span_in_original(string, &normed, match_begin..match_begin + match_len),
+                exact: found_exact_surname,
+            })
+        } else {
+            None
+        }
+    }
+
+    /// Does this name appear to match an email address, social media handle
+    /// or profile URL?
+    ///
+    /// Like `matches_slug_or_localpart`, but first extracts the localpart of
+    /// an email address (ignoring plus-addressing, as in "jane+news@"), the
+    /// handle (as in "@janedoe"), or the last segment of a URL's path, and
+    /// ignores digit suffixes (as in "janedoe2005" or "jane-doe-4a5b6c").
+    /// Role accounts, like "info@" or "admin@", never match.
+    ///
+    /// ```
+    /// use human_name::Name;
+    /// let name = Name::parse("Jane A. Doe").unwrap();
+    ///
+    /// assert!(name.matches_web_identifier("Jane.Doe+news@example.com"));
+    /// assert!(name.matches_web_identifier("janedoe2005@example.com"));
+    /// assert!(name.matches_web_identifier("@janedoe"));
+    /// assert!(name.matches_web_identifier("https://www.example.com/in/jane-doe-4a5b6c/"));
+    /// assert!(name.matches_web_identifier("example.com/people/jdoe?ref=home"));
+    ///
+    /// assert!(!name.matches_web_identifier("info@janedoe.com"));
+    /// assert!(!name.matches_web_identifier("https://janedoe.com"));
+    /// assert!(!name.matches_web_identifier("john.doe@example.com"));
+    /// ```
+    pub fn matches_web_identifier(&self, identifier: &str) -> bool {
+        match slug_in_web_identifier(identifier) {
+            Some(slug) => self.matches_slug_or_localpart(slug),
+            None => false,
+        }
     }
 
     fn find_surname_in(&self, haystack: &str) -> Option<(usize, usize, bool)> {
@@ -171,7 +261,7 @@ impl Name {
 
     fn matches_remaining_name_parts(&self, part: &str, allow_unknowns: bool) -> bool {
         let lower_first_initial = self.first_initial().to_lowercase().next().unwrap();
-        let given_names: Option<Cow<str>> = if self.surname_index == 1 {
+        let given_names: Option<Cow<str>> = if self.given_names_end() == 1 {
             self.given_name().map(|w| Cow::Borrowed(w))
         } else if self.surname_index > 0 {
             Some(join(self.given_iter()))
@@ -247,3 +337,148 @@ impl Name {
         false
     }
 }
+
+// Maps a range of the letters of a string, as lowercased in `slug_match`,
+// back to the string itself
+fn span_in_original(original: &str, letters: &str, range: Range<usize>) -> Range<usize> {
+    let skipped = letters[..range.start].chars().count();
+    let len = letters[range].chars().count();
+
+    let positions: Vec<(usize, char)> = original
+        .char_indices()
+        .filter(|&(_, c)| lowercase_if_alpha(c).is_some())
+        .collect();
+    let (start, _) = positions[skipped];
+    let (last, c) = positions[skipped + len - 1];
+
+    start..last + c.len_utf8()
+}
+
+// The localpart of an email address, a handle, or the last segment of a
+// URL's path, without any digit suffix, unless it's a role account
+fn slug_in_web_identifier(identifier: &str) -> Option<&str> {
+    let identifier = identifier.trim();
+    let identifier = if identifier.starts_with("mailto:") {
+        &identifier["mailto:".len()..]
+    } else {
+        identifier
+    };
+
+    let slug = if let Some(i) = identifier.find("://") {
+        last_path_segment(&identifier[i + "://".len()..])?
+    } else if identifier.starts_with('@') {
+        &identifier[1..]
+    } else if let Some(i) = identifier.rfind('@') {
+        identifier[..i].split('+').next().unwrap()
+    } else if identifier.contains('/') {
+        last_path_segment(identifier)?
+    } else {
+        identifier
+    };
+
+    let slug = without_digit_suffix(slug);
+    if slug.is_empty() || ROLE_ACCOUNTS.contains(&*slug.to_lowercase()) {
+        None
+    } else {
+        Some(slug)
+    }
+}
+
+// Expects a URL without its scheme
+fn last_path_segment(url: &str) -> Option<&str> {
+    let path = url.splitn(2, '/').nth(1)?;
+    path.split(|c| c == '?' || c == '#')
+        .next()
+        .unwrap()
+        .rsplit('/')
+        .find(|segment| !segment.is_empty())
+}
+
+// Drops trailing digits, and any trailing words containing digits, as in
+// the "4a5b6c" some sites add to profile URLs
+fn without_digit_suffix(slug: &str) -> &str {
+    let mut slug = slug;
+    loop {
+        slug = slug.trim_end_matches(|c: char| c.is_ascii_digit() || is_nonalphanumeric(c));
+
+        let last_word_start = slug
+            .char_indices()
+            .rev()
+            .find(|&(_, c)| is_nonalphanumeric(c))
+            .map_or(0, |(i, c)| i + c.len_utf8());
+
+        if last_word_start > 0 && slug[last_word_start..].chars().any(|c| c.is_ascii_digit()) {
+            slug = &slug[..last_word_start];
+        } else {
+            return slug;
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn slug_match(name: &str, string: &str) -> Option<SlugMatch> {
+        Name::parse(name).unwrap().slug_match(string)
+    }
+
+    #[test]
+    fn surname_span() {
+        assert_eq!(
+            Some(SlugMatch::Surname {
+                span: 2..5,
+                exact: true
+            }),
+            slug_match("Jane Doe", "J2Doe")
+        );
+        assert_eq!(
+            Some(SlugMatch::Surname {
+                span: 4..8,
+                exact: false
+            }),
+            slug_match("Jane Doeberg", "janedoeb")
+        );
+    }
+
+    #[test]
+    fn web_identifiers() {
+        assert_eq!(
+            Some("jane.doe"),
+            slug_in_web_identifier("jane.doe@example.com")
+        );
+        assert_eq!(
+            Some("jdoe"),
+            slug_in_web_identifier("mailto:jdoe+news@example.com")
+        );
+        assert_eq!(Some("janedoe"), slug_in_web_identifier(" @janedoe "));
+        assert_eq!(
+            Some("jane-doe"),
+            slug_in_web_identifier("https://example.com/in/jane-doe-4a5b6c/#about")
+        );
+        assert_eq!(
+            Some("@janedoe"),
+            slug_in_web_identifier("https://example.com/@janedoe")
+        );
+        assert_eq!(Some("ank.agr"), slug_in_web_identifier("ank.agr89"));
+        assert_eq!(Some("pitt"), slug_in_web_identifier("pitt.2"));
+    }
+
+    #[test]
+    fn not_web_identifiers() {
+        assert_eq!(None, slug_in_web_identifier("https://example.com/"));
+        assert_eq!(None, slug_in_web_identifier("example.com/?q=jane"));
+        assert_eq!(None, slug_in_web_identifier("Info@example.com"));
+        assert_eq!(None, slug_in_web_identifier("no-reply2@example.com"));
+        assert_eq!(None, slug_in_web_identifier("2005@example.com"));
+        assert_eq!(None, slug_in_web_identifier(""));
+    }
+}
+
+static ROLE_ACCOUNTS: phf::Set<&'static str> = phf_set! {
+    "abuse", "accounts", "admin", "administrator", "billing", "careers", "contact", "contactus",
+    "enquiries", "feedback", "hello", "help", "helpdesk", "hostmaster", "hr", "info", "inquiries",
+    "jobs", "legal", "mail", "marketing", "media", "news", "newsletter", "no-reply", "noreply",
+    "office", "postmaster", "press", "privacy", "root", "sales", "security", "service", "support",
+    "team", "webmaster",
+};
@@ -1,4 +1,6 @@
 extern crate human_name;
+#[cfg(feature = "serde")]
+extern crate serde_json;
 extern crate unicode_normalization;
 
 use std::fs::File;
@@ -89,6 +91,117 @@ fn parsing() {
     }
 }
 
+#[cfg(feature = "serde")]
+#[test]
+fn serde_round_trip() {
+    let f = File::open("tests/parseable-names.txt").ok().unwrap();
+    let reader = BufReader::new(f);
+
+    for line in reader.lines() {
+        let line = line.ok().unwrap();
+
+        if line.starts_with("#") || !line.contains("|") {
+            continue;
+        }
+
+        let input = line.split("|").nth(0).unwrap();
+        let name = human_name::Name::parse(input).unwrap();
+        let json = serde_json::to_string(&name).unwrap();
+        let deserialized: human_name::Name = serde_json::from_str(&json)
+            .unwrap_or_else(|e| panic!("[{}] Could not deserialize {}: {}", input, json, e));
+
+        assert!(
+            name.display_full() == deserialized.display_full()
+                && name.initials() == deserialized.initials()
+                && name.hash == deserialized.hash,
+            "[{}] Expected {}, got {}",
+            input,
+            name.display_full(),
+            deserialized.display_full()
+        );
+        assert_eq!(json, serde_json::to_string(&deserialized).unwrap());
+    }
+}
+
+#[test]
+fn spans() {
+    let f = File::open("tests/parseable-names.txt").ok().unwrap();
+    let reader = BufReader::new(f);
+    let all_options = [
+        human_name::ParseOptions::default(),
+        human_name::ParseOptions {
+            iberian_surnames: true,
+            ..Default::default()
+        },
+        human_name::ParseOptions {
+            name_order: human_name::NameOrder::SurnameFirst,
+            ..Default::default()
+        },
+        human_name::ParseOptions {
+            name_order: human_name::NameOrder::Auto,
+            iberian_surnames: true,
+            arabic_patronymics: true,
+        },
+    ];
+    let letters = |s: &str| -> String {
+        s.nfkd()
+            .filter(|c| c.is_alphabetic())
+            .flat_map(char::to_lowercase)
+            .collect()
+    };
+
+    for line in reader.lines() {
+        let line = line.ok().unwrap();
+
+        if line.starts_with("#") || !line.contains("|") {
+            continue;
+        }
+
+        let input = line.split("|").nth(0).unwrap();
+
+        for options in &all_options {
+            let name = match human_name::Name::try_parse_with_options(input, options) {
+                Ok(name) => name,
+                Err(e) => {
+                    assert_eq!(
+                        Err(e),
+                        human_name::Name::try_parse_with_spans(input, options).map(|_| ()),
+                        "[{}] {:?}",
+                        input,
+                        options
+                    );
+                    continue;
+                }
+            };
+            let (with_spans, spans) = human_name::Name::try_parse_with_spans(input, options)
+                .unwrap_or_else(|e| panic!("[{}] Could not parse with spans: {}", input, e));
+
+            assert_eq!(
+                name.display_full(),
+                with_spans.display_full(),
+                "[{}] {:?}",
+                input,
+                options
+            );
+            assert_eq!(name.hash, with_spans.hash, "[{}] {:?}", input, options);
+
+            let surnames: Vec<&str> = spans.surnames.iter().map(|r| &input[r.clone()]).collect();
+            assert_eq!(
+                letters(&name.surnames().join(" ")),
+                letters(&surnames.join(" ")),
+                "[{}] {:?} Surname spans {:?}",
+                input,
+                options,
+                surnames
+            );
+
+            if let (Some(nickname), Some(span)) = (name.nickname(), spans.nickname) {
+                assert_eq!(letters(nickname), letters(&input[span]), "[{}]", input);
+            }
+        }
+    }
+}
+
 #[test]
 fn unparseable() {
     let f = File::open("tests/unparseable-names.txt").ok().unwrap();
@@ -219,3 +332,130 @@ fn web_nonmatch() {
         );
     }
 }
+
+// As they might appear in email addresses and profile URLs
+fn web_identifiers(slug: &str) -> Vec<String> {
+    vec![
+        format!("{}@example.com", slug),
+        format!("{}+news@example.com", slug),
+        format!("https://example.com/people/{}/", slug),
+        format!("example.com/{}?ref=home", slug),
+    ]
+}
+
+#[test]
+fn web_identifier_match() {
+    let f = File::open("tests/web-matches.txt").ok().unwrap();
+    let reader = BufReader::new(f);
+
+    for line in reader.lines() {
+        let line = line.ok().unwrap();
+
+        if line.starts_with("#") {
+            continue;
+        }
+
+        let parts: Vec<&str> = line.split('|').collect();
+
+        let name = human_name::Name::parse(parts[1]).unwrap();
+        for compare in web_identifiers(parts[0]) {
+            assert!(
+                name.matches_web_identifier(&compare),
+                "{} should match {} but did not!",
+                name.display_full(),
+                compare
+            );
+        }
+    }
+}
+
+#[test]
+fn web_identifier_nonmatch() {
+    let f = File::open("tests/web-nonmatches.txt").ok().unwrap();
+    let reader = BufReader::new(f);
+
+    for line in reader.lines() {
+        let line = line.ok().unwrap();
+
+        if line.starts_with("#") {
+            continue;
+        }
+
+        let parts: Vec<&str> = line.split('|').collect();
+
+        let name = human_name::Name::parse(parts[1]).unwrap();
+        for compare in web_identifiers(parts[0]) {
+            assert!(
+                !name.matches_web_identifier(&compare),
+                "{} should not match {} but did!",
+                name.display_full(),
+                compare
+            );
+        }
+    }
+}
+
+#[test]
+fn slug_candidates() {
+    let f = File::open("tests/web-matches.txt").ok().unwrap();
+    let reader = BufReader::new(f);
+
+    for line in reader.lines() {
+        let line = line.ok().unwrap();
+
+        if line.starts_with("#") {
+            continue;
+        }
+
+        let parts: Vec<&str> = line.split('|').collect();
+        let name = human_name::Name::parse(parts[1]).unwrap();
+
+        for candidate in name.slug_candidates() {
+            assert!(
+                name.matches_slug_or_localpart(&candidate),
+                "{} should match {} but did not!",
+                name.display_full(),
+                candidate
+            );
+        }
+    }
+}
+
+#[test]
+fn ranking() {
+    let f = File::open("tests/ranked-names.txt").ok().unwrap();
+    let reader = BufReader::new(f);
+
+    for line in reader.lines() {
+        let line = line.ok().unwrap();
+
+        if line.starts_with("#") {
+            continue;
+        }
+
+        let parts: Vec<&str> = line.split('|').collect();
+        let name = human_name::Name::parse(parts[0]).unwrap();
+        let better = human_name::Name::parse(parts[1]).unwrap();
+        let better_score = name.similarity(&better);
+
+        assert!(
+            better_score.is_match(),
+            "{} should match {} but did not!",
+            parts[0],
+            parts[1]
+        );
+
+        if let Some(worse) = human_name::Name::parse(parts[2]) {
+            let worse_score = name.similarity(&worse);
+            assert!(
+                better_score > worse_score,
+                "{} should be more similar to {} ({:?}) than {} ({:?})!",
+                parts[0],
+                parts[1],
+                better_score,
+                parts[2],
+                worse_score
+            );
+        }
+    }
+}